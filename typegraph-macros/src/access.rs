@@ -0,0 +1,124 @@
+use quote::format_ident;
+use syn::visit::{self, Visit};
+use syn::{BinOp, Expr, Ident, Member};
+
+/// The fields of `self` read and written in `block`, in order of first access.
+///
+/// Assignments (including compound ones) and `&mut` borrows count as writes. Whether a method
+/// called on a field takes `&mut self` can't be told from the call, so in `&mut self` methods, which
+/// can mutate their fields, method calls on a field count as both a read and a write. Everything
+/// else counts as a read.
+pub fn traced(block: &syn::Block, mutable: bool) -> (Vec<Ident>, Vec<Ident>) {
+    let mut visitor = Visitor {
+        mutable,
+        ..Default::default()
+    };
+    visitor.visit_block(block);
+
+    (visitor.reads, visitor.writes)
+}
+
+#[derive(Default)]
+struct Visitor {
+    mutable: bool,
+    reads: Vec<Ident>,
+    writes: Vec<Ident>,
+}
+
+impl Visitor {
+    fn write(&mut self, place: &Expr) {
+        match place {
+            Expr::Field(f) if is_self(&f.base) => push(&mut self.writes, &f.member),
+            Expr::Field(f) => self.write(&f.base),
+            Expr::Index(i) => {
+                self.write(&i.expr);
+                self.visit_expr(&i.index);
+            }
+            Expr::Paren(p) => self.write(&p.expr),
+            place => self.visit_expr(place),
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for Visitor {
+    fn visit_expr_field(&mut self, i: &'ast syn::ExprField) {
+        if is_self(&i.base) {
+            push(&mut self.reads, &i.member);
+        } else {
+            visit::visit_expr_field(self, i);
+        }
+    }
+
+    fn visit_expr_assign(&mut self, i: &'ast syn::ExprAssign) {
+        self.write(&i.left);
+        self.visit_expr(&i.right);
+    }
+
+    fn visit_expr_binary(&mut self, i: &'ast syn::ExprBinary) {
+        if is_compound_assignment(&i.op) {
+            self.write(&i.left);
+        }
+
+        visit::visit_expr_binary(self, i);
+    }
+
+    fn visit_expr_method_call(&mut self, i: &'ast syn::ExprMethodCall) {
+        if self.mutable && is_self_place(&i.receiver) {
+            self.write(&i.receiver);
+        }
+
+        visit::visit_expr_method_call(self, i);
+    }
+
+    fn visit_expr_reference(&mut self, i: &'ast syn::ExprReference) {
+        if i.mutability.is_some() {
+            self.write(&i.expr);
+        } else {
+            visit::visit_expr_reference(self, i);
+        }
+    }
+
+    // Nested items have their own bodies and their own `self`.
+    fn visit_item(&mut self, _: &'ast syn::Item) {}
+}
+
+fn is_self(expr: &Expr) -> bool {
+    matches!(expr, Expr::Path(p) if p.path.is_ident("self"))
+}
+
+/// Whether `expr` is a field of `self`, or a place within one.
+fn is_self_place(expr: &Expr) -> bool {
+    match expr {
+        Expr::Field(f) => is_self(&f.base) || is_self_place(&f.base),
+        Expr::Index(i) => is_self_place(&i.expr),
+        Expr::Paren(p) => is_self_place(&p.expr),
+        _ => false,
+    }
+}
+
+fn is_compound_assignment(op: &BinOp) -> bool {
+    matches!(
+        op,
+        BinOp::AddAssign(_)
+            | BinOp::SubAssign(_)
+            | BinOp::MulAssign(_)
+            | BinOp::DivAssign(_)
+            | BinOp::RemAssign(_)
+            | BinOp::BitXorAssign(_)
+            | BinOp::BitAndAssign(_)
+            | BinOp::BitOrAssign(_)
+            | BinOp::ShlAssign(_)
+            | BinOp::ShrAssign(_)
+    )
+}
+
+/// Fields are named as in the derive, tuple fields as `_0`, `_1`, ...
+fn push(fields: &mut Vec<Ident>, member: &Member) {
+    let name = match member {
+        Member::Named(ident) => ident.clone(),
+        Member::Unnamed(index) => format_ident!("_{}", index.index),
+    };
+    if !fields.contains(&name) {
+        fields.push(name);
+    }
+}
@@ -0,0 +1,165 @@
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Ident, Type};
+
+use crate::{argument, generic, id, NODE_DATA_LABEL};
+
+#[derive(deluxe::ParseMetaItem, Default)]
+struct Attributes {
+    #[deluxe(default)]
+    generics: Vec<Type>,
+    #[deluxe(default)]
+    transparent: bool,
+    meta: Option<Type>,
+    cluster: Option<syn::Path>,
+    path: Option<syn::Path>,
+}
+
+/// The marker type generated for the type alias `ident`, e.g. `AppResultAlias` for `AppResult`.
+pub fn marker_name(ident: &Ident) -> Ident {
+    format_ident!("{}Alias", ident)
+}
+
+pub fn alias_macro(attr: TokenStream, item: syn::ItemType) -> TokenStream {
+    let Attributes {
+        generics,
+        transparent,
+        meta,
+        cluster,
+        path: module,
+    } = match deluxe::parse(attr) {
+        Ok(desc) => desc,
+        Err(e) => return e.into_compile_error().into(),
+    };
+    if !generics.is_empty() && generics.len() != item.generics.type_params().count() {
+        return syn::Error::new_spanned(
+            item.generics,
+            "The number of typegraph generic substitutions differs from the number of generic type parameters.",
+        )
+        .to_compile_error()
+        .into();
+    }
+    if transparent {
+        let conflict = match (&cluster, &meta) {
+            (Some(cluster), _) => Some(quote! { #cluster }),
+            (_, Some(meta)) => Some(quote! { #meta }),
+            _ => None,
+        };
+        if let Some(conflict) = conflict {
+            return syn::Error::new_spanned(
+                conflict,
+                "A transparent typegraph alias has no node of its own, so it can't have a cluster or meta.",
+            )
+            .to_compile_error()
+            .into();
+        }
+    }
+    if !argument::is_supported(&item.ty) {
+        return syn::Error::new_spanned(
+            item.ty,
+            "Only paths, trait objects, references, pointers, slices, arrays and tuples can be aliased by a typegraph node.",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    if cfg!(feature = "inert") {
+        return quote! { #item }.into();
+    }
+
+    let ident = &item.ident;
+    let marker = marker_name(ident);
+    let path = id::item_path("alias", module.as_ref(), &[ident]);
+    let mut generic_sub = match generic::Substitution::new(generics, &item.generics, &marker, &path)
+    {
+        Ok(sub) => sub,
+        Err(tt) => return tt,
+    };
+    let target = generic_sub.substitute((*item.ty).clone());
+    let placeholders = generic_sub.placeholders();
+    let vis = &item.vis;
+
+    // A transparent alias is its target: the marker resolves to the target's node.
+    if transparent {
+        return quote! {
+            #item
+            #placeholders
+
+            #vis struct #marker;
+            impl ::typegraph::Typegraph for #marker {
+                type Id = <#target as ::typegraph::Typegraph>::Id;
+                type Node = <#target as ::typegraph::Typegraph>::Node;
+                type Nodes = <#target as ::typegraph::Typegraph>::Nodes;
+                type Edges = <#target as ::typegraph::Typegraph>::Edges;
+                type Types = <#target as ::typegraph::Typegraph>::Types;
+            }
+        }
+        .into();
+    }
+
+    let node_id = match id::node_id(&path, ident.span()) {
+        Ok(node_id) => node_id,
+        Err(tt) => return tt,
+    };
+    let mod_id = format_ident!("typegraph_nodealias_{}", ident.to_string().to_lowercase());
+    let node_label = format_ident!("{}Alias{}", NODE_DATA_LABEL, ident);
+    let metadata = match &meta {
+        Some(ty) => quote! { #ty },
+        None => quote! { #mod_id::#node_label },
+    };
+
+    #[cfg(feature = "value")]
+    let value_impl = {
+        let generics = &item.generics;
+        let subgraph = cluster
+            .into_iter()
+            .flat_map(|s| s.segments.into_iter().map(|p| p.ident))
+            .collect::<Vec<_>>();
+        quote! {
+            impl ::typegraph::Value<::typegraph::NodeKind> for #mod_id::#node_label {
+                fn value() -> ::typegraph::NodeKind {
+                    ::typegraph::NodeKind::Alias(
+                        stringify!(type #ident #generics),
+                        &[#(stringify!(#subgraph)),*],
+                    )
+                }
+            }
+        }
+    };
+    #[cfg(not(feature = "value"))]
+    let value_impl = quote! {};
+
+    quote! {
+        #item
+        #placeholders
+
+        #vis struct #marker;
+        impl ::typegraph::Typegraph for #marker {
+            type Id = #mod_id::ids::AliasNodeId;
+            type Node = #mod_id::#node_label;
+            type Nodes = ::typegraph::set![::typegraph::NodeOutput<Self::Id, #mod_id::#node_label, #metadata>];
+            type Edges = ::typegraph::set![::typegraph::TypedEdge<Self::Id, <#target as ::typegraph::Typegraph>::Id, ::typegraph::edge::Aliases>];
+            type Types = ::typegraph::list![#target];
+        }
+
+        #value_impl
+
+        mod #mod_id {
+            use ::typegraph::*;
+
+            pub struct #node_label;
+            impl NodeOutputData for #node_label {
+                const ID: u32 = <ids::AliasNodeId as Unsigned>::U32;
+                const KIND: NodeOutputKind = NodeOutputKind::Alias;
+                const NAME: &'static str = stringify!(#node_label);
+                const PATH: &'static str = #path;
+            }
+
+            pub mod ids {
+                use ::typegraph::num::*;
+                pub type AliasNodeId = #node_id;
+            }
+        }
+    }
+    .into()
+}
@@ -1,4 +1,9 @@
-use syn::{FnArg, Ident, ImplItemFn, Pat, Type};
+use syn::{
+    FnArg, Ident, Pat, Signature, Type, TypeArray, TypeGroup, TypeImplTrait, TypeParen, TypePtr,
+    TypeReference, TypeSlice, TypeTraitObject, TypeTuple,
+};
+
+use crate::generic;
 
 #[derive(deluxe::ParseMetaItem, deluxe::ExtractAttributes, Default)]
 #[deluxe(attributes(typegraph))]
@@ -9,10 +14,10 @@ struct Attributes {
     skip: bool,
 }
 
-pub fn extract_impl_fn(item: &mut ImplItemFn) -> Vec<(Ident, Type)> {
+pub fn extract_fn(sig: &mut Signature) -> Vec<(Ident, Type)> {
     let mut calls = vec![];
 
-    for input in item.sig.inputs.iter_mut() {
+    for input in sig.inputs.iter_mut() {
         match input {
             FnArg::Receiver(_receiver) => {}
             FnArg::Typed(pat_type) => {
@@ -25,12 +30,12 @@ pub fn extract_impl_fn(item: &mut ImplItemFn) -> Vec<(Ident, Type)> {
                 let Pat::Ident(p) = &*pat_type.pat else {
                     continue;
                 };
-                let Type::Path(ty) = &*pat_type.ty else {
+                if force.is_none() && !is_supported(&pat_type.ty) {
                     continue;
-                };
+                }
                 calls.push((
                     p.ident.clone(),
-                    force.unwrap_or_else(|| Type::Path(ty.clone())),
+                    force.unwrap_or_else(|| (*pat_type.ty).clone()),
                 ));
             }
         }
@@ -38,3 +43,23 @@ pub fn extract_impl_fn(item: &mut ImplItemFn) -> Vec<(Ident, Type)> {
 
     calls
 }
+
+/// Whether `ty` can be graphed as written: a path, `dyn Trait` or `impl Trait`, or a reference,
+/// raw pointer, slice, array or tuple of them.
+pub fn is_supported(ty: &Type) -> bool {
+    match ty {
+        Type::Path(_) => true,
+        Type::TraitObject(TypeTraitObject { bounds, .. })
+        | Type::ImplTrait(TypeImplTrait { bounds, .. }) => {
+            !generic::bound_traits(bounds).is_empty()
+        }
+        Type::Reference(TypeReference { elem, .. })
+        | Type::Ptr(TypePtr { elem, .. })
+        | Type::Slice(TypeSlice { elem, .. })
+        | Type::Array(TypeArray { elem, .. })
+        | Type::Paren(TypeParen { elem, .. })
+        | Type::Group(TypeGroup { elem, .. }) => is_supported(elem),
+        Type::Tuple(TypeTuple { elems, .. }) => elems.iter().all(is_supported),
+        _ => false,
+    }
+}
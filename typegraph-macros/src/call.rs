@@ -0,0 +1,106 @@
+use quote::{format_ident, quote};
+use syn::visit::{self, Visit};
+use syn::{Expr, Ident, Path};
+
+use crate::function;
+
+/// The methods and associated functions of `self_ident` called in `block`, as far as they can be
+/// told apart syntactically: `self.f()`, `Self::f()` and `SelfIdent::f()`.
+///
+/// Free functions aren't traced: whether a path names a `#[typegraph]` function depends on items
+/// the macro can't see, so they are listed with `calls` instead.
+pub fn traced(block: &syn::Block, self_ident: &Ident) -> Vec<Ident> {
+    let mut visitor = Visitor {
+        self_ident,
+        called: vec![],
+    };
+    visitor.visit_block(block);
+
+    visitor.called
+}
+
+struct Visitor<'a> {
+    self_ident: &'a Ident,
+    called: Vec<Ident>,
+}
+
+impl<'ast> Visit<'ast> for Visitor<'_> {
+    fn visit_expr_method_call(&mut self, i: &'ast syn::ExprMethodCall) {
+        if let Expr::Path(receiver) = &*i.receiver {
+            if receiver.path.is_ident("self") && !self.called.contains(&i.method) {
+                self.called.push(i.method.clone());
+            }
+        }
+
+        visit::visit_expr_method_call(self, i);
+    }
+
+    fn visit_expr_call(&mut self, i: &'ast syn::ExprCall) {
+        if let Expr::Path(func) = &*i.func {
+            let segments = &func.path.segments;
+            if func.qself.is_none()
+                && segments.len() == 2
+                && (segments[0].ident == "Self" || segments[0].ident == *self.self_ident)
+                && !self.called.contains(&segments[1].ident)
+            {
+                self.called.push(segments[1].ident.clone());
+            }
+        }
+
+        visit::visit_expr_call(self, i);
+    }
+
+    // Nested items have their own bodies and their own `Self`.
+    fn visit_item(&mut self, _: &'ast syn::Item) {}
+}
+
+/// A callee named in `#[typegraph(calls = [...])]`.
+pub struct Callee {
+    /// The callee's node ID.
+    pub id: proc_macro2::TokenStream,
+    /// The type whose resolution brings the callee into the graph.
+    pub ty: proc_macro2::TokenStream,
+    /// Definitions the ID relies on, to be placed in the caller's `ids` module.
+    pub ids: proc_macro2::TokenStream,
+}
+
+impl Callee {
+    /// `Type::method` (the second to last segment is capitalized) resolves through
+    /// `typegraph::MethodNode`, anything else is taken to be a free function and resolves through
+    /// its marker type.
+    pub fn new(path: &Path, caller_mod: &Ident, n: usize) -> Option<Self> {
+        let mut segments = path.segments.iter().rev();
+        let name = segments.next()?.ident.clone();
+        let owner = segments.next().map(|seg| seg.ident.to_string());
+        Some(match owner {
+            Some(owner) if owner.starts_with(char::is_uppercase) => {
+                let mut owner_path = path.clone();
+                owner_path.segments.pop();
+                owner_path.segments.pop_punct();
+                let alias = format_ident!("CalleeName{}", n);
+                let hash: proc_macro2::TokenStream =
+                    crate::id::hashed(quote! { #name }.into()).into();
+
+                Self {
+                    id: quote! {
+                        <#owner_path as ::typegraph::MethodNode<#caller_mod::ids::#alias>>::Id
+                    },
+                    ty: quote! { #owner_path },
+                    ids: quote! { pub type #alias = #hash; },
+                }
+            }
+            _ => {
+                let mut marker = path.clone();
+                if let Some(last) = marker.segments.last_mut() {
+                    last.ident = function::marker_name(&name);
+                }
+
+                Self {
+                    id: quote! { <#marker as ::typegraph::Typegraph>::Id },
+                    ty: quote! { #marker },
+                    ids: quote! {},
+                }
+            }
+        })
+    }
+}
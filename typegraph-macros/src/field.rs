@@ -1,8 +1,7 @@
 use proc_macro::TokenStream;
-use quote::format_ident;
-use syn::{Ident, PathArguments, Type};
+use syn::{Ident, Type};
 
-use crate::{Outcome, NODE_DATA_LABEL};
+use crate::{argument, Outcome};
 
 #[derive(deluxe::ParseMetaItem, deluxe::ExtractAttributes, Default)]
 #[deluxe(attributes(typegraph))]
@@ -28,32 +27,13 @@ impl State {
             return Ok(Outcome::Skip);
         }
 
-        let mut tp =
-            match field.ty.clone() {
-                Type::Path(tp) => tp,
-                Type::Reference(tr) => {
-                    let Type::Path(tp) = *tr.elem else {
-                        return Err(syn::Error::new_spanned(field, "Unsupported type reference")
-                            .to_compile_error()
-                            .into());
-                    };
-                    tp
-                }
-                _ => return Err(syn::Error::new_spanned(
-                    field,
-                    "Typegraph fields must either be paths, or have a path explicitly provided.",
-                )
-                .to_compile_error()
-                .into()),
-            };
-        let edge_label = format_ident!(
-            "{}{}",
-            NODE_DATA_LABEL,
-            tp.path.segments.last().map(|seg| &seg.ident).unwrap() // TODO
-        );
-        if let Some(l) = tp.path.segments.last_mut() {
-            l.ident = edge_label;
-            l.arguments = PathArguments::None;
+        if force.is_none() && !argument::is_supported(&field.ty) {
+            return Err(syn::Error::new_spanned(
+                field,
+                "Typegraph fields must be paths or trait objects, or references, pointers, slices, arrays or tuples of them, or have a type explicitly provided.",
+            )
+            .to_compile_error()
+            .into());
         }
 
         Ok(Outcome::Connect(Self {
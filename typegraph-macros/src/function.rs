@@ -1,10 +1,11 @@
+use itertools::Itertools;
 use proc_macro::TokenStream;
 use quote::{format_ident, quote};
 use syn::{Ident, Type};
 
 use crate::{
-    argument, generic,
-    id::{hashed, uid},
+    access, argument, call, generic,
+    id::{self, hashed},
     Outcome, NODE_DATA_LABEL,
 };
 
@@ -19,10 +20,29 @@ struct Attributes {
     skip_ret: bool,
     #[deluxe(default)]
     skip: bool,
+    #[deluxe(default)]
+    calls: Vec<syn::Path>,
+}
+
+#[derive(deluxe::ParseMetaItem, Default)]
+struct FreeAttributes {
+    #[deluxe(default)]
+    generics: Vec<Type>,
+    #[deluxe(default)]
+    force_ret: Option<Type>,
+    #[deluxe(default)]
+    skip_ret: bool,
+    #[deluxe(default)]
+    calls: Vec<syn::Path>,
+    meta: Option<Type>,
+    #[cfg_attr(not(feature = "value"), allow(dead_code))]
+    cluster: Option<syn::Path>,
+    path: Option<syn::Path>,
 }
 
 pub struct State {
     pub id: proc_macro2::TokenStream,
+    pub path: String,
     pub mod_id: Ident,
     pub return_type: Option<Type>,
     pub name: proc_macro2::TokenStream,
@@ -30,42 +50,40 @@ pub struct State {
     pub trait_name: Ident,
     pub kind: proc_macro2::TokenStream,
     pub output_kind: proc_macro2::TokenStream,
+    pub edge_kind: proc_macro2::TokenStream,
     pub arg_names: Vec<proc_macro2::TokenStream>,
     pub arg_ids: Vec<proc_macro2::TokenStream>,
     pub arg_types: Vec<Type>,
+    pub calls: Vec<syn::Path>,
+    pub field_reads: Vec<Ident>,
+    pub field_writes: Vec<Ident>,
+    pub placeholders: proc_macro2::TokenStream,
 }
 
 impl State {
     pub fn try_from_fn(
         function: &mut syn::ImplItemFn,
+        self_ty: &Type,
         ident: &Ident,
         id: &Ident,
+        module: Option<&syn::Path>,
+        trace_fields: bool,
     ) -> Result<Outcome<Self>, TokenStream> {
-        let Attributes {
-            generics,
-            force_ret,
-            skip_ret,
-            skip,
-        } = deluxe::extract_attributes(function).unwrap_or_default();
-        if !generics.is_empty() && generics.len() != function.sig.generics.type_params().count() {
-            return Err(syn::Error::new_spanned(
-                function,
-                "The number of typegraph generic substitutions differs from the number of generic type parameters.",
-            )
-            .to_compile_error()
-            .into());
-        }
-        if skip {
+        let attributes: Attributes = deluxe::extract_attributes(function).unwrap_or_default();
+        if attributes.skip {
             return Ok(Outcome::Skip);
         }
+        let (field_reads, field_writes) = if trace_fields {
+            let mutable = function
+                .sig
+                .receiver()
+                .is_some_and(|r| r.reference.is_some() && r.mutability.is_some());
+            access::traced(&function.block, mutable)
+        } else {
+            (vec![], vec![])
+        };
 
-        let mut generic_sub = generic::Substitution::new(generics, &function.sig.generics);
-
-        let mut chars = function.sig.ident.to_string().chars().collect::<Vec<_>>();
-        chars[0] = chars[0].to_uppercase().next().unwrap_or('a');
-        let capitalized = chars.into_iter().filter(|c| *c != '_').collect::<String>();
         let name = function.sig.ident.clone();
-        let generics = function.sig.generics.clone();
         let mod_id = format_ident!(
             "typegraph_{}_nodeimpl_{}_{}",
             ident.to_string().to_lowercase(),
@@ -76,17 +94,134 @@ impl State {
             "{}Impl{}Method{}",
             ident,
             id,
-            function
-                .sig
-                .ident
-                .to_string()
+            name.to_string()
+                .chars()
+                .filter(|c| *c != '_')
+                .collect::<String>()
+        );
+        let fns_name = format_ident!(
+            "{}Method{}{}{}",
+            NODE_DATA_LABEL,
+            ident,
+            capitalized(&name),
+            id
+        );
+
+        Self::try_from_sig(
+            attributes,
+            &mut function.sig,
+            Some(self_ty),
+            &id::item_path("fn", module, &[ident, id, &name]),
+            mod_id,
+            trait_name,
+            fns_name,
+        )
+        .map(|state| {
+            Outcome::Connect(Self {
+                field_reads,
+                field_writes,
+                ..state
+            })
+        })
+    }
+
+    /// Builds the state for a method declared by the trait `ident`, whose `Self` is the trait's
+    /// marker type.
+    pub fn try_from_trait_fn(
+        function: &mut syn::TraitItemFn,
+        marker: &Type,
+        ident: &Ident,
+        module: Option<&syn::Path>,
+    ) -> Result<Outcome<Self>, TokenStream> {
+        let attributes: Attributes = deluxe::extract_attributes(function).unwrap_or_default();
+        if attributes.skip {
+            return Ok(Outcome::Skip);
+        }
+
+        let name = function.sig.ident.clone();
+        let mod_id = format_ident!(
+            "typegraph_nodetrait_{}_{}",
+            ident.to_string().to_lowercase(),
+            name.to_string().to_lowercase(),
+        );
+        let trait_name = format_ident!(
+            "{}TraitMethod{}",
+            ident,
+            name.to_string()
                 .chars()
                 .filter(|c| *c != '_')
                 .collect::<String>()
         );
-        let fns_name = format_ident!("{}Method{}{}{}", NODE_DATA_LABEL, ident, capitalized, id);
+        let fns_name = format_ident!("{}Method{}{}", NODE_DATA_LABEL, ident, capitalized(&name));
 
-        let args = argument::extract_impl_fn(function);
+        Self::try_from_sig(
+            attributes,
+            &mut function.sig,
+            Some(marker),
+            &id::item_path("fn", module, &[ident, &name]),
+            mod_id,
+            trait_name,
+            fns_name,
+        )
+        .map(Outcome::Connect)
+    }
+
+    /// Builds the state for a free function, whose `trait_name` is the generated marker type.
+    fn try_from_free_fn(
+        function: &mut syn::ItemFn,
+        attributes: Attributes,
+        module: Option<&syn::Path>,
+    ) -> Result<Self, TokenStream> {
+        let name = function.sig.ident.clone();
+        let mod_id = format_ident!("typegraph_nodefn_{}", name.to_string().to_lowercase());
+        let marker = marker_name(&name);
+        let fns_name = format_ident!("{}{}", NODE_DATA_LABEL, marker);
+
+        Self::try_from_sig(
+            attributes,
+            &mut function.sig,
+            None,
+            &id::item_path("fn", module, &[&name]),
+            mod_id,
+            marker,
+            fns_name,
+        )
+    }
+
+    fn try_from_sig(
+        Attributes {
+            generics,
+            force_ret,
+            skip_ret,
+            calls,
+            ..
+        }: Attributes,
+        sig: &mut syn::Signature,
+        self_ty: Option<&Type>,
+        path: &str,
+        mod_id: Ident,
+        trait_name: Ident,
+        fns_name: Ident,
+    ) -> Result<Self, TokenStream> {
+        if !generics.is_empty() && generics.len() != sig.generics.type_params().count() {
+            return Err(syn::Error::new_spanned(
+                sig,
+                "The number of typegraph generic substitutions differs from the number of generic type parameters.",
+            )
+            .to_compile_error()
+            .into());
+        }
+
+        let mut generic_sub =
+            generic::Substitution::new(generics, &sig.generics, &trait_name, path)?;
+        if let Some(self_ty) = self_ty {
+            generic_sub = generic_sub.with_self(self_ty);
+        }
+
+        let name = sig.ident.clone();
+        let generics = sig.generics.clone();
+
+        let args = argument::extract_fn(sig);
         let arg_names = args.iter().map(|(n, _)| quote! { #n }).collect::<Vec<_>>();
         let (arg_ids, arg_types): (Vec<proc_macro2::TokenStream>, Vec<_>) = args
             .into_iter()
@@ -97,26 +232,29 @@ impl State {
             if skip_ret {
                 None
             } else {
-                match &function.sig.output {
+                match &sig.output {
                     syn::ReturnType::Default => None,
                     syn::ReturnType::Type(_, t) => Some(generic_sub.substitute(*t.clone())),
                 }
             }
         });
-        let (kind, output_kind) = if function.sig.asyncness.is_some() {
+        let (kind, output_kind, edge_kind) = if sig.asyncness.is_some() {
             (
                 quote! { NodeKind::AsyncFunction },
                 quote! { NodeOutputKind::AsyncFunction },
+                quote! { ::typegraph::edge::AsyncMethodOf },
             )
         } else {
             (
                 quote! { NodeKind::Function },
                 quote! { NodeOutputKind::Function },
+                quote! { ::typegraph::edge::MethodOf },
             )
         };
 
-        Ok(Outcome::Connect(Self {
-            id: uid(),
+        Ok(Self {
+            id: id::node_id(path, name.span())?,
+            path: path.to_string(),
             mod_id,
             return_type,
             name: quote! { #name #generics },
@@ -124,9 +262,170 @@ impl State {
             trait_name,
             kind,
             output_kind,
+            edge_kind,
             arg_names,
             arg_ids,
             arg_types: generic_sub.substitute_all(arg_types),
-        }))
+            calls,
+            field_reads: vec![],
+            field_writes: vec![],
+            placeholders: generic_sub.placeholders(),
+        })
+    }
+}
+
+/// The marker type generated for the free function `name`, e.g. `LoadUserFn` for `load_user`.
+pub fn marker_name(name: &Ident) -> Ident {
+    let camel = name
+        .to_string()
+        .split('_')
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            let mut chars = s.chars();
+            chars
+                .next()
+                .map(|c| c.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect::<String>();
+
+    format_ident!("{}Fn", camel)
+}
+
+fn capitalized(name: &Ident) -> String {
+    let mut chars = name.to_string().chars().collect::<Vec<_>>();
+    chars[0] = chars[0].to_uppercase().next().unwrap_or('a');
+    chars.into_iter().filter(|c| *c != '_').collect::<String>()
+}
+
+pub fn fn_macro(attr: TokenStream, mut function: syn::ItemFn) -> TokenStream {
+    let FreeAttributes {
+        generics,
+        force_ret,
+        skip_ret,
+        calls,
+        meta,
+        #[cfg(feature = "value")]
+        cluster,
+        path: module,
+        ..
+    } = match deluxe::parse(attr) {
+        Ok(desc) => desc,
+        Err(e) => return e.into_compile_error().into(),
+    };
+    let attributes = Attributes {
+        generics,
+        force_ret,
+        skip_ret,
+        skip: false,
+        calls,
+    };
+    let state = match State::try_from_free_fn(&mut function, attributes, module.as_ref()) {
+        Ok(state) => state,
+        Err(tt) => return tt,
+    };
+
+    if cfg!(feature = "inert") {
+        return quote! { #function }.into();
+    }
+
+    let State {
+        id,
+        path,
+        mod_id,
+        return_type,
+        #[cfg(feature = "value")]
+        name,
+        fns_name,
+        trait_name: marker,
+        #[cfg(feature = "value")]
+        kind,
+        output_kind,
+        #[cfg(feature = "value")]
+        arg_names,
+        arg_types,
+        calls,
+        placeholders,
+        ..
+    } = state;
+    let (callee_ids, callee_tys, callee_id_defs) = calls
+        .iter()
+        .enumerate()
+        .filter_map(|(n, path)| call::Callee::new(path, &mod_id, n))
+        .map(|c| (c.id, c.ty, c.ids))
+        .multiunzip::<(Vec<_>, Vec<_>, Vec<_>)>();
+
+    let metadata = match &meta {
+        Some(ty) => quote! { #ty },
+        None => quote! { #mod_id::#fns_name },
+    };
+    let return_types = return_type.into_iter().collect::<Vec<_>>();
+    let types = arg_types
+        .iter()
+        .chain(&return_types)
+        .cloned()
+        .unique()
+        .collect::<Vec<_>>();
+
+    #[cfg(feature = "value")]
+    let value_impl = {
+        let subgraph = cluster
+            .into_iter()
+            .flat_map(|s| s.segments.into_iter().map(|p| p.ident))
+            .collect::<Vec<_>>();
+        quote! {
+            impl ::typegraph::Value<::typegraph::NodeKind> for #mod_id::#fns_name {
+                fn value() -> ::typegraph::NodeKind {
+                    ::typegraph::#kind(
+                        stringify!(#name),
+                        &[#(stringify!(#subgraph)),*],
+                        &[#((stringify!(#arg_names), <<#arg_types as ::typegraph::Typegraph>::Id as ::typegraph::Unsigned>::U32)),*]
+                    )
+                }
+            }
+        }
+    };
+    #[cfg(not(feature = "value"))]
+    let value_impl = quote! {};
+
+    quote! {
+        #function
+        #placeholders
+
+        // Public like the structs the attribute macro emits, as the call edges of other items
+        // name it.
+        pub struct #marker;
+        impl ::typegraph::Typegraph for #marker {
+            type Id = #mod_id::ids::FnNodeId;
+            type Node = #mod_id::#fns_name;
+            type Nodes = ::typegraph::set![::typegraph::NodeOutput<Self::Id, #mod_id::#fns_name, #metadata>];
+            type Edges = ::typegraph::merge_sets![
+                ::typegraph::set![#(::typegraph::TypedEdge<Self::Id, <#return_types as ::typegraph::Typegraph>::Id, ::typegraph::edge::Provides>),*],
+                ::typegraph::set![#(::typegraph::TypedEdge<<#arg_types as ::typegraph::Typegraph>::Id, Self::Id, ::typegraph::edge::ArgumentOf>),*],
+                ::typegraph::set![#(::typegraph::TypedEdge<Self::Id, #callee_ids, ::typegraph::edge::Invokes>),*]
+            ];
+            type Types = ::typegraph::list![#(#types,)* #(#callee_tys),*];
+        }
+
+        #value_impl
+
+        mod #mod_id {
+            use ::typegraph::*;
+
+            pub struct #fns_name;
+            impl NodeOutputData for #fns_name {
+                const ID: u32 = <ids::FnNodeId as Unsigned>::U32;
+                const KIND: NodeOutputKind = #output_kind;
+                const NAME: &'static str = stringify!(#fns_name);
+                const PATH: &'static str = #path;
+            }
+
+            pub mod ids {
+                use ::typegraph::num::*;
+                pub type FnNodeId = #id;
+                #(#callee_id_defs)*
+            }
+        }
     }
+    .into()
 }
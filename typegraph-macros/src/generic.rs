@@ -1,13 +1,114 @@
 use std::collections::HashMap;
 
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
 use syn::visit_mut::{self, VisitMut};
-use syn::{Ident, Type};
+use syn::{
+    Ident, Lifetime, PathArguments, TraitBound, TraitBoundModifier, Type, TypeParam,
+    TypeParamBound, WherePredicate,
+};
 
-pub struct Substitution(HashMap<Ident, Type>);
+use crate::{id, implementation, NODE_DATA_LABEL};
+
+/// Rewrites types for use outside of the item they were written in: generic parameters are
+/// replaced by their typegraph substitutions, a trait's associated types (`Self::Name`) by
+/// theirs, `Self` by the implementing type, `dyn Trait` and
+/// `impl Trait` by the node of the (first) trait, and every lifetime (elided or not) by
+/// `'static`, since node IDs don't depend on lifetimes.
+pub struct Substitution {
+    generics: HashMap<Ident, Type>,
+    associated: HashMap<Ident, Type>,
+    self_ty: Option<Type>,
+    scope: Ident,
+    traits: Vec<Ident>,
+    placeholders: Vec<proc_macro2::TokenStream>,
+}
 
 impl Substitution {
-    pub fn new(v: Vec<Type>, g: &syn::Generics) -> Self {
-        Self(g.type_params().map(|tp| tp.ident.clone()).zip(v).collect())
+    /// Substitutes the type parameters of `g` with `v`, in order. Without explicit substitutions,
+    /// a placeholder node is synthesized for each parameter, named `{scope}Generic{param}` and
+    /// identified by the path of the item it belongs to.
+    pub fn new(
+        v: Vec<Type>,
+        g: &syn::Generics,
+        scope: &Ident,
+        path: &str,
+    ) -> Result<Self, TokenStream> {
+        if !v.is_empty() {
+            return Ok(Self {
+                generics: g.type_params().map(|tp| tp.ident.clone()).zip(v).collect(),
+                associated: HashMap::new(),
+                self_ty: None,
+                scope: scope.clone(),
+                traits: vec![],
+                placeholders: vec![],
+            });
+        }
+
+        let mut generics = HashMap::new();
+        let mut placeholders = vec![];
+        for param in g.type_params() {
+            let ident = format_ident!("{}Generic{}", scope, param.ident);
+            placeholders.push(placeholder(&ident, param, g, path)?);
+            generics.insert(param.ident.clone(), syn::parse_quote! { #ident });
+        }
+
+        Ok(Self {
+            generics,
+            associated: HashMap::new(),
+            self_ty: None,
+            scope: scope.clone(),
+            traits: vec![],
+            placeholders,
+        })
+    }
+
+    /// The definitions of the synthesized placeholder nodes, to be emitted next to the item.
+    pub fn placeholders(&self) -> proc_macro2::TokenStream {
+        let placeholders = &self.placeholders;
+        quote! { #(#placeholders)* }
+    }
+
+    pub fn with_self(self, self_ty: &Type) -> Self {
+        Self {
+            self_ty: Some(self_ty.clone()),
+            ..self
+        }
+    }
+
+    /// Treats the parameters `names` as associated types, only substituted when named through
+    /// `Self` (`Self::Name` or `<Self as Trait>::Name`), so that other types of the same name,
+    /// like `io::Error` next to `type Error`, are left alone.
+    pub fn with_associated<'a>(mut self, names: impl IntoIterator<Item = &'a Ident>) -> Self {
+        for name in names {
+            if let Some(ty) = self.generics.remove(name) {
+                self.associated.insert(name.clone(), ty);
+            }
+        }
+        self
+    }
+
+    /// The substitution of `i` if it names an associated type through `Self`, or through the
+    /// trait's marker that `Self` was already substituted by in the signature of a trait method.
+    fn associated_type(&self, i: &syn::TypePath) -> Option<&Type> {
+        let is_self = |ty: &Type| match ty {
+            Type::Path(p) => {
+                p.qself.is_none() && (p.path.is_ident("Self") || p.path.is_ident(&self.scope))
+            }
+            _ => false,
+        };
+        let segments = &i.path.segments;
+        let through_self = match &i.qself {
+            Some(q) => is_self(&q.ty) && segments.len() == q.position + 1,
+            None => segments.len() == 2 && segments[0].ident == "Self",
+        };
+        if !through_self {
+            return None;
+        }
+        segments
+            .last()
+            .filter(|s| s.arguments.is_none())
+            .and_then(|s| self.associated.get(&s.ident))
     }
 
     pub fn substitute_all(&mut self, mut fn_types: Vec<Type>) -> Vec<Type> {
@@ -22,16 +123,235 @@ impl Substitution {
         self.visit_type_mut(&mut ty);
         ty
     }
+
+    /// The node of the trait `ident`, named by `path`, emitted once per scope next to the
+    /// placeholders. It shares the ID of every other node of the trait, like those of trait impls.
+    fn trait_node(&mut self, ident: &Ident, path: &syn::Path) -> Type {
+        let mod_label = format_ident!(
+            "typegraph_{}_dyn_{}",
+            self.scope.to_string().to_lowercase(),
+            ident.to_string().to_lowercase()
+        );
+        let node_label = format_ident!("{}Trait{}", NODE_DATA_LABEL, ident);
+        if !self.traits.contains(ident) {
+            self.traits.push(ident.clone());
+            let node =
+                implementation::trait_node(&node_label, ident, path, quote! { ids::TraitNodeId });
+            self.placeholders
+                .push(match id::node_id(&id::trait_path(path), ident.span()) {
+                    Ok(trait_id) => quote! {
+                        mod #mod_label {
+                            use typegraph::*;
+
+                            #node
+
+                            pub mod ids {
+                                use typegraph::num::*;
+                                pub type TraitNodeId = #trait_id;
+                            }
+                        }
+                    },
+                    Err(tt) => tt.into(),
+                });
+        }
+
+        syn::parse_quote! { #mod_label::#node_label }
+    }
 }
 
 impl VisitMut for Substitution {
+    fn visit_type_mut(&mut self, i: &mut Type) {
+        let bounds = match i {
+            Type::TraitObject(t) => &t.bounds,
+            Type::ImplTrait(t) => &t.bounds,
+            _ => return visit_mut::visit_type_mut(self, i),
+        };
+        if let Some((t, path, _)) = bound_traits(bounds).first() {
+            let (t, path) = ((*t).clone(), (*path).clone());
+            *i = self.trait_node(&t, &path);
+        }
+    }
+
     fn visit_type_path_mut(&mut self, i: &mut syn::TypePath) {
+        if let Some(Type::Path(ty)) = self.associated_type(i) {
+            *i = ty.clone();
+            return;
+        }
+        if i.qself.is_none() && i.path.is_ident("Self") {
+            if let Some(Type::Path(self_ty)) = &self.self_ty {
+                *i = self_ty.clone();
+            }
+        }
         if let Some(segment) = i.path.segments.last_mut() {
-            if let Some(Type::Path(ref_path)) = self.0.get(&segment.ident) {
+            if let Some(Type::Path(ref_path)) = self.generics.get(&segment.ident) {
                 *i = ref_path.clone();
             }
         }
 
         visit_mut::visit_type_path_mut(self, i);
     }
+
+    fn visit_type_reference_mut(&mut self, i: &mut syn::TypeReference) {
+        i.lifetime = Some(Lifetime::new("'static", i.and_token.span));
+        visit_mut::visit_type_reference_mut(self, i);
+    }
+
+    fn visit_lifetime_mut(&mut self, i: &mut Lifetime) {
+        *i = Lifetime::new("'static", i.apostrophe);
+    }
+}
+
+/// A unit struct standing in for the generic parameter `param`, labeled with the parameter and
+/// the bounds it is declared with, inline or in the where-clause. Each trait bound is also a
+/// `Bound` edge to the trait's node, labeled with the trait's generic arguments and associated
+/// type constraints, if any.
+fn placeholder(
+    ident: &Ident,
+    param: &TypeParam,
+    g: &syn::Generics,
+    path: &str,
+) -> Result<proc_macro2::TokenStream, TokenStream> {
+    let name = &param.ident;
+    let path = format!("{path}<{name}>");
+    let node_id = id::node_id(&path, name.span())?;
+    let mod_label = format_ident!("typegraph_{}", ident.to_string().to_lowercase());
+    let bounds = bounds(param, g);
+
+    let traits = bound_traits(bounds.iter().copied());
+    let trait_node_labels = traits
+        .iter()
+        .map(|(t, _, _)| format_ident!("{}Trait{}", NODE_DATA_LABEL, t))
+        .collect::<Vec<_>>();
+    let trait_id_labels = traits
+        .iter()
+        .map(|(t, _, _)| format_ident!("Trait{}Id", t))
+        .collect::<Vec<_>>();
+    let trait_ids = traits
+        .iter()
+        .map(|(t, path, _)| id::node_id(&id::trait_path(path), t.span()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let trait_nodes = traits
+        .iter()
+        .zip(&trait_node_labels)
+        .zip(&trait_id_labels)
+        .map(|(((t, path, _), label), id_label)| {
+            implementation::trait_node(label, t, path, quote! { ids::#id_label })
+        });
+
+    #[cfg(feature = "value")]
+    let value_impl = {
+        let label = if bounds.is_empty() {
+            quote! { stringify!(#name) }
+        } else {
+            quote! { concat!(stringify!(#name), ": ", stringify!(#(#bounds)+*)) }
+        };
+        let (bound_labels, bound_ids): (Vec<_>, Vec<_>) = traits
+            .iter()
+            .zip(&trait_id_labels)
+            .filter_map(|((_, _, args), id_label)| args.as_ref().map(|a| (a, id_label)))
+            .unzip();
+        quote! {
+            impl ::typegraph::Value<::typegraph::NodeKind> for #ident {
+                fn value() -> ::typegraph::NodeKind {
+                    ::typegraph::NodeKind::Generic(
+                        #label,
+                        &[],
+                        &[#((stringify!(#bound_labels), <#mod_label::ids::#bound_ids as ::typegraph::Unsigned>::U32)),*]
+                    )
+                }
+            }
+        }
+    };
+    #[cfg(not(feature = "value"))]
+    let value_impl = quote! {};
+
+    Ok(quote! {
+        #[doc(hidden)]
+        pub struct #ident;
+        impl ::typegraph::NodeOutputData for #ident {
+            const ID: u32 = <#mod_label::ids::NodeId as ::typegraph::Unsigned>::U32;
+            const KIND: ::typegraph::NodeOutputKind = ::typegraph::NodeOutputKind::Generic;
+            const NAME: &'static str = stringify!(#name);
+            const PATH: &'static str = #path;
+        }
+        impl ::typegraph::Typegraph for #ident {
+            type Id = #mod_label::ids::NodeId;
+            type Node = Self;
+            type Nodes = ::typegraph::set![::typegraph::NodeOutput<Self::Id, Self, Self>];
+            type Edges = ::typegraph::set![#(::typegraph::TypedEdge<Self::Id, #mod_label::ids::#trait_id_labels, ::typegraph::edge::Bound>),*];
+            type Types = ::typegraph::list![#(#mod_label::#trait_node_labels),*];
+        }
+        #value_impl
+
+        mod #mod_label {
+            use typegraph::*;
+
+            #(#trait_nodes)*
+
+            pub mod ids {
+                use typegraph::num::*;
+                pub type NodeId = #node_id;
+                #(pub type #trait_id_labels = #trait_ids;)*
+            }
+        }
+    })
+}
+
+/// The bounds of `param`, followed by those of where-clause predicates on exactly `param`.
+fn bounds<'a>(param: &'a TypeParam, g: &'a syn::Generics) -> Vec<&'a TypeParamBound> {
+    let predicates = g
+        .where_clause
+        .iter()
+        .flat_map(|w| &w.predicates)
+        .filter_map(|p| match p {
+            WherePredicate::Type(p) => match &p.bounded_ty {
+                Type::Path(ty) if ty.qself.is_none() && ty.path.is_ident(&param.ident) => {
+                    Some(&p.bounds)
+                }
+                _ => None,
+            },
+            _ => None,
+        });
+
+    param.bounds.iter().chain(predicates.flatten()).collect()
+}
+
+/// The traits among `bounds`, each with its path as written and its generic arguments or
+/// parenthesized signature, if any. `?Sized` and lifetimes aren't traits a node can point to, so
+/// they're left out.
+pub fn bound_traits<'a>(
+    bounds: impl IntoIterator<Item = &'a TypeParamBound>,
+) -> Vec<(&'a Ident, &'a syn::Path, Option<proc_macro2::TokenStream>)> {
+    let mut traits: Vec<(&Ident, &syn::Path, Option<proc_macro2::TokenStream>)> = vec![];
+    for bound in bounds {
+        let TypeParamBound::Trait(TraitBound {
+            modifier: TraitBoundModifier::None,
+            path,
+            ..
+        }) = bound
+        else {
+            continue;
+        };
+        let Some(segment) = path.segments.last() else {
+            continue;
+        };
+        if traits.iter().any(|(t, _, _)| *t == &segment.ident) {
+            continue;
+        }
+        traits.push((&segment.ident, path, arguments(segment)));
+    }
+
+    traits
+}
+
+/// The generic arguments or parenthesized signature of a trait named by `segment`, if any.
+pub fn arguments(segment: &syn::PathSegment) -> Option<proc_macro2::TokenStream> {
+    match &segment.arguments {
+        PathArguments::None => None,
+        PathArguments::AngleBracketed(a) => {
+            let args = &a.args;
+            Some(quote! { #args })
+        }
+        PathArguments::Parenthesized(a) => Some(quote! { #a }),
+    }
 }
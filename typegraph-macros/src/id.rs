@@ -1,12 +1,161 @@
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
 use proc_macro::{Punct, Spacing, TokenStream, TokenTree};
-use quote::quote;
-use rand::RngCore;
-use std::hash::{DefaultHasher, Hasher};
+use syn::Ident;
+
+use crate::{MAX_NODE_IDS, RESERVED_NODE_IDS};
+
+/// Every ID handed out while expanding the current crate, along with the path it was derived from.
+static REGISTRY: Mutex<BTreeMap<u32, String>> = Mutex::new(BTreeMap::new());
+
+/// The path a node ID is derived from, e.g. `fn typegraph_test::test::Dinos::B::epic`.
+pub fn item_path(kind: &str, module: Option<&syn::Path>, segments: &[&Ident]) -> String {
+    let path = module_path(module)
+        .into_iter()
+        .chain(segments.iter().map(ToString::to_string))
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join("::");
+    format!("{kind} {path}")
+}
+
+/// The crate and modules of an item, e.g. `["typegraph_test", "test"]`.
+///
+/// Proc macros cannot see the module an item is declared in, so unless it is given explicitly by
+/// the item's `path` attribute, the module is approximated from the source file of the call site.
+pub fn module_path(module: Option<&syn::Path>) -> Vec<String> {
+    let krate = std::env::var("CARGO_CRATE_NAME").unwrap_or_default();
+    let modules = match module {
+        Some(module) => module
+            .segments
+            .iter()
+            .map(|seg| seg.ident.to_string())
+            .collect(),
+        None => file_modules(),
+    };
+
+    std::iter::once(krate).chain(modules).collect()
+}
 
-use crate::MAX_NODE_IDS;
+fn file_modules() -> Vec<String> {
+    let file = proc_macro::Span::call_site().file();
+    let components = file
+        .trim_end_matches(".rs")
+        .rsplit(['/', '\\'])
+        .collect::<Vec<_>>();
+    let mut modules = match components.iter().position(|s| *s == "src") {
+        Some(src) => components[..src].to_vec(),
+        None => components.into_iter().take(1).collect(),
+    };
+    modules.reverse();
+    if matches!(modules.last(), Some(&"lib" | &"main" | &"mod")) {
+        modules.pop();
+    }
+
+    modules.into_iter().map(ToString::to_string).collect()
+}
+
+/// The path a trait node ID is derived from, e.g. `trait typegraph_test::Carnivore`, for the trait
+/// named by `path`.
+///
+/// Traits of `std`, `core` and `alloc`, named by their full path or, for those of the prelude, by
+/// their bare name, get a key of their own whatever the crate (`trait std::clone::Clone`), so the
+/// impls of every crate share one node. Other traits are named by whatever path is in scope
+/// (`Carnivore`, `super::Carnivore`, ...) and `use` declarations can't be resolved, so only the
+/// crate and the trait's own name are used: traits sharing a name within a crate share a node. So
+/// do `From<A>` and `From<B>`, whose arguments are kept on the `implements` edges of their impls.
+pub fn trait_path(path: &syn::Path) -> String {
+    let segments = path
+        .segments
+        .iter()
+        .map(|seg| seg.ident.to_string())
+        .collect::<Vec<_>>();
+    let ident = segments.last().map(String::as_str).unwrap_or_default();
+    match (segments.as_slice(), prelude_module(ident)) {
+        ([krate, rest @ ..], _)
+            if !rest.is_empty() && ["std", "core", "alloc"].contains(&krate.as_str()) =>
+        {
+            format!("trait std::{}", rest.join("::"))
+        }
+        ([_], Some(module)) => format!("trait std::{module}::{ident}"),
+        _ => {
+            let krate = std::env::var("CARGO_CRATE_NAME").unwrap_or_default();
+            format!("trait {krate}::{ident}")
+        }
+    }
+}
 
-pub fn uid() -> proc_macro2::TokenStream {
-    new(quote! {}.into()).into()
+/// The module of `std` that the prelude trait `ident` is defined in.
+fn prelude_module(ident: &str) -> Option<&'static str> {
+    Some(match ident {
+        "Copy" | "Send" | "Sized" | "Sync" | "Unpin" => "marker",
+        "Drop" | "Fn" | "FnMut" | "FnOnce" => "ops",
+        "AsMut" | "AsRef" | "From" | "Into" | "TryFrom" | "TryInto" => "convert",
+        "DoubleEndedIterator"
+        | "ExactSizeIterator"
+        | "Extend"
+        | "FromIterator"
+        | "IntoIterator"
+        | "Iterator" => "iter",
+        "Clone" => "clone",
+        "Eq" | "Ord" | "PartialEq" | "PartialOrd" => "cmp",
+        "Default" => "default",
+        "ToOwned" => "borrow",
+        "ToString" => "string",
+        _ => return None,
+    })
+}
+
+/// The path a remote proxy's node ID is derived from, e.g. `remote chrono::Weekday`.
+///
+/// Only the foreign path is used, so every proxy for the same foreign type shares its node.
+pub fn remote_path(path: &syn::Path) -> String {
+    let path = quote::ToTokens::to_token_stream(path).to_string();
+    format!("remote {}", path.replace(' ', ""))
+}
+
+/// A deterministic node ID for the node at `path`. Items with the same path, like types of the same
+/// name in different inline modules of one file, share their node, as do the nodes emitted for the
+/// same trait or remote type by several items.
+///
+/// Fails if a different path expanded in this crate has already been assigned the same ID, since
+/// the two nodes would otherwise be silently merged into one. Expanding the same path again, as
+/// language servers do on every edit, is fine.
+pub fn node_id(
+    path: &str,
+    span: proc_macro2::Span,
+) -> Result<proc_macro2::TokenStream, TokenStream> {
+    let id = hash(path);
+    let mut registry = REGISTRY.lock().unwrap_or_else(|e| e.into_inner());
+    match registry.get(&id) {
+        Some(existing) if existing != path => {
+            return Err(syn::Error::new(
+                span,
+                format!("typegraph node ID collision: `{existing}` and `{path}` both hash to {id}. Rename one of them."),
+            )
+            .to_compile_error()
+            .into());
+        }
+        Some(_) => {}
+        None => {
+            registry.insert(id, path.to_string());
+        }
+    }
+
+    Ok(u32_to_tokenstream(id, TokenStream::new()).into())
+}
+
+fn hash(path: &str) -> u32 {
+    RESERVED_NODE_IDS
+        + (fnv1a(path.as_bytes()) % u64::from(MAX_NODE_IDS - RESERVED_NODE_IDS)) as u32
+}
+
+/// 64-bit FNV-1a, used instead of `DefaultHasher` because its output is stable across Rust releases.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, b| {
+        (hash ^ u64::from(*b)).wrapping_mul(0x100000001b3)
+    })
 }
 
 #[allow(unused)]
@@ -86,7 +235,7 @@ impl TypenumUint {
 
 fn u32_to_tokenstream(n: u32, _prefix: TokenStream) -> TokenStream {
     let mut result = TokenStream::new();
-    TypenumUint::from(n % MAX_NODE_IDS).write_ts(&mut result);
+    TypenumUint::from(n).write_ts(&mut result);
     result
 }
 
@@ -110,18 +259,8 @@ fn split_off_prefix(args: TokenStream) -> (TokenStream, TokenStream) {
     (local, prefix)
 }
 
-pub fn new(args: TokenStream) -> TokenStream {
-    let (args, prefix) = split_off_prefix(args);
-    assert!(args.is_empty(), "new IDs take no arguments");
-    u32_to_tokenstream(rand::rng().next_u32(), prefix)
-}
-
 pub fn hashed(args: TokenStream) -> TokenStream {
     let (args, prefix) = split_off_prefix(args);
-    use std::hash::Hash;
-    let s = args.to_string();
-    let mut hasher = DefaultHasher::new();
-    s.hash(&mut hasher);
-    let n = hasher.finish();
-    u32_to_tokenstream((n % u32::MAX as u64) as u32, prefix)
+    let n = fnv1a(args.to_string().as_bytes());
+    u32_to_tokenstream((n % u64::from(MAX_NODE_IDS)) as u32, prefix)
 }
@@ -3,8 +3,7 @@ use proc_macro::TokenStream;
 use quote::{format_ident, quote};
 use syn::{Ident, ImplItem, Type, TypePath};
 
-use crate::id::uid;
-use crate::{function, Outcome};
+use crate::{argument, call, function, id, Outcome};
 use crate::{generic, NODE_DATA_LABEL};
 
 #[derive(deluxe::ParseMetaItem, deluxe::ExtractAttributes)]
@@ -16,6 +15,11 @@ struct Attributes {
     cluster: Option<syn::Path>,
     #[deluxe(default)]
     generics: Vec<Type>,
+    #[deluxe(default)]
+    trace_calls: bool,
+    #[deluxe(default)]
+    trace_fields: bool,
+    path: Option<syn::Path>,
 }
 #[derive(deluxe::ParseMetaItem, deluxe::ExtractAttributes)]
 #[deluxe(attributes(typegraph))]
@@ -27,6 +31,9 @@ impl From<Attributes2> for Attributes {
             meta: value.1,
             cluster: value.2,
             generics: value.3.unwrap_or_default(),
+            trace_calls: false,
+            trace_fields: false,
+            path: None,
         }
     }
 }
@@ -42,12 +49,21 @@ struct State {
     fn_kinds: Vec<proc_macro2::TokenStream>,
     fn_mod_ids: Vec<Ident>,
     fn_output_kinds: Vec<proc_macro2::TokenStream>,
+    fn_edge_kinds: Vec<proc_macro2::TokenStream>,
     fn_return_types: Vec<Option<syn::Type>>,
     fn_ids: Vec<proc_macro2::TokenStream>,
+    fn_paths: Vec<String>,
+    fn_idents: Vec<Ident>,
+    fn_calls: Vec<Vec<syn::Path>>,
+    fn_traced_calls: Vec<Vec<Ident>>,
+    fn_field_reads: Vec<Vec<Ident>>,
+    fn_field_writes: Vec<Vec<Ident>>,
+    fn_placeholders: Vec<proc_macro2::TokenStream>,
+    associated: Vec<(Type, String)>,
 }
 
 impl State {
-    fn add_fn(&mut self, f: function::State) {
+    fn add_fn(&mut self, f: function::State, ident: Ident, traced_calls: Vec<Ident>) {
         self.arg_ids.push(f.arg_ids);
         self.arg_names.push(f.arg_names);
         self.arg_types.push(f.arg_types);
@@ -56,9 +72,26 @@ impl State {
         self.fn_trait_names.push(f.trait_name);
         self.fn_kinds.push(f.kind);
         self.fn_output_kinds.push(f.output_kind);
+        self.fn_edge_kinds.push(f.edge_kind);
         self.fn_return_types.push(f.return_type);
         self.fn_ids.push(f.id);
+        self.fn_paths.push(f.path);
         self.fn_mod_ids.push(f.mod_id);
+        self.fn_idents.push(ident);
+        self.fn_calls.push(f.calls);
+        self.fn_field_reads.push(f.field_reads);
+        self.fn_field_writes.push(f.field_writes);
+        self.fn_traced_calls.push(traced_calls);
+        self.fn_placeholders.push(f.placeholders);
+    }
+
+    /// Associated items of the same type share an edge, labeled with all of their names.
+    fn add_associated(&mut self, ty: Type, name: String) {
+        if let Some((_, s)) = self.associated.iter_mut().find(|(t, _)| *t == ty) {
+            *s = format!("{s}, {name}");
+        } else {
+            self.associated.push((ty, name));
+        }
     }
 }
 
@@ -68,6 +101,9 @@ pub fn impl_macro(attr: TokenStream, mut implementation: syn::ItemImpl) -> Token
         meta,
         cluster,
         generics,
+        trace_calls,
+        trace_fields,
+        path: module,
     } = match deluxe::parse(attr.clone())
         .or_else(|_| deluxe::parse::<Attributes2>(attr.clone()).map(Into::into))
     {
@@ -88,7 +124,6 @@ pub fn impl_macro(attr: TokenStream, mut implementation: syn::ItemImpl) -> Token
     //} else {
     //    quote! { #ty_generics }
     //};
-    let mut generic_sub = generic::Substitution::new(generics, &implementation.generics);
     let self_ty = implementation.self_ty.clone();
     let ident = match &*self_ty {
         Type::Path(TypePath { path, .. }) => path.segments.last().map(|seg| seg.ident.clone()),
@@ -98,27 +133,72 @@ pub fn impl_macro(attr: TokenStream, mut implementation: syn::ItemImpl) -> Token
     .unwrap_or(format_ident!("unknown"));
     let impl_node_label = format_ident!("{}Impl{}{}", NODE_DATA_LABEL, ident, id);
     let impl_trait_name = format_ident!("{}Impl{}", ident, id);
-    let impl_id = uid();
+    let impl_path = id::item_path("impl", module.as_ref(), &[&ident, &id]);
+    let impl_name_id: proc_macro2::TokenStream = id::hashed(quote! { #id }.into()).into();
+    let impl_id = match id::node_id(&impl_path, id.span()) {
+        Ok(impl_id) => impl_id,
+        Err(tt) => return tt,
+    };
+    let mut generic_sub = match generic::Substitution::new(
+        generics,
+        &implementation.generics,
+        &impl_trait_name,
+        &impl_path,
+    ) {
+        Ok(sub) => sub,
+        Err(tt) => return tt,
+    };
+    let trait_ident = implementation
+        .trait_
+        .as_ref()
+        .and_then(|(_, path, _)| path.segments.last().map(|seg| seg.ident.clone()));
+    let trait_id = match (&implementation.trait_, &trait_ident) {
+        (Some((_, path, _)), Some(t)) => match id::node_id(&id::trait_path(path), t.span()) {
+            Ok(trait_id) => Some(trait_id),
+            Err(tt) => return tt,
+        },
+        _ => None,
+    };
 
     let mut state = State::default();
     for item in &mut implementation.items {
-        if let ImplItem::Fn(f) = item {
-            match function::State::try_from_fn(f, &ident, &id) {
-                Ok(Outcome::Skip) => {
-                    continue;
-                }
-                Ok(Outcome::Connect(f)) => {
-                    state.add_fn(f);
-                }
-                Err(tt) => {
-                    return tt;
+        match item {
+            ImplItem::Type(t) if argument::is_supported(&t.ty) => {
+                state.add_associated(t.ty.clone(), format!("type {}", t.ident));
+            }
+            ImplItem::Const(c) if argument::is_supported(&c.ty) => {
+                state.add_associated(c.ty.clone(), format!("const {}", c.ident));
+            }
+            ImplItem::Fn(f) => {
+                match function::State::try_from_fn(
+                    f,
+                    &self_ty,
+                    &ident,
+                    &id,
+                    module.as_ref(),
+                    trace_fields,
+                ) {
+                    Ok(Outcome::Skip) => {
+                        continue;
+                    }
+                    Ok(Outcome::Connect(s)) => {
+                        let traced_calls = if trace_calls {
+                            call::traced(&f.block, &ident)
+                        } else {
+                            vec![]
+                        };
+                        state.add_fn(s, f.sig.ident.clone(), traced_calls);
+                    }
+                    Err(tt) => {
+                        return tt;
+                    }
                 }
             }
+            _ => {}
         }
     }
 
-    #[cfg(feature = "inert")]
-    {
+    if cfg!(feature = "inert") {
         return quote! { #implementation }.into();
     }
 
@@ -138,6 +218,11 @@ pub fn impl_macro(attr: TokenStream, mut implementation: syn::ItemImpl) -> Token
         .map(|_| subgraph.clone())
         .collect::<Vec<_>>();
 
+    let arg_types = state
+        .arg_types
+        .into_iter()
+        .map(|tys| generic_sub.substitute_all(tys))
+        .collect::<Vec<_>>();
     let return_types = state
         .fn_return_types
         .into_iter()
@@ -147,16 +232,16 @@ pub fn impl_macro(attr: TokenStream, mut implementation: syn::ItemImpl) -> Token
         })
         .collect::<Vec<_>>();
 
-    let arg_edges = state
-        .arg_types
+    let arg_edges = arg_types
         .iter()
         .map(|v_ty| {
             v_ty.iter()
                 .map(|ty| {
                     quote! {
-                        ::typegraph::Edge<
+                        ::typegraph::TypedEdge<
                             <#ty as ::typegraph::Typegraph>::Id,
                             Self::Id,
+                            ::typegraph::edge::ArgumentOf,
                         >
                     }
                 })
@@ -169,9 +254,10 @@ pub fn impl_macro(attr: TokenStream, mut implementation: syn::ItemImpl) -> Token
             v_ty.iter()
                 .map(|ty| {
                     quote! {
-                        ::typegraph::Edge<
+                        ::typegraph::TypedEdge<
                             Self::Id,
                             <#ty as ::typegraph::Typegraph>::Id,
+                            ::typegraph::edge::Provides,
                         >
                     }
                 })
@@ -179,16 +265,9 @@ pub fn impl_macro(attr: TokenStream, mut implementation: syn::ItemImpl) -> Token
         })
         .collect::<Vec<_>>();
 
-    let flat_arg_types = state
-        .arg_types
-        .iter()
-        .flatten()
-        .cloned()
-        .unique()
-        .map(|ty| generic_sub.substitute(ty))
-        .collect::<Vec<_>>();
+    let flat_arg_types = arg_types.iter().flatten().cloned().unique();
     let flat_ret_types = return_types.iter().flatten().cloned().unique();
-    let arg_ret_types = flat_arg_types.into_iter().chain(flat_ret_types);
+    let arg_ret_types = flat_arg_types.chain(flat_ret_types);
 
     let State {
         fn_trait_names,
@@ -196,12 +275,112 @@ pub fn impl_macro(attr: TokenStream, mut implementation: syn::ItemImpl) -> Token
         fn_kinds,
         fn_names,
         fn_ids,
+        fn_paths,
         fn_mod_ids,
         fn_output_kinds,
+        fn_edge_kinds,
         arg_names,
-        arg_types,
+        fn_idents,
+        fn_calls,
+        fn_traced_calls,
+        fn_field_reads,
+        fn_field_writes,
+        fn_placeholders,
+        associated,
         ..
     } = state;
+    let associated_tys = associated
+        .iter()
+        .map(|(ty, _)| generic_sub.substitute(ty.clone()))
+        .collect::<Vec<_>>();
+    #[cfg(feature = "value")]
+    let associated_names = associated.iter().map(|(_, name)| name).collect::<Vec<_>>();
+    let placeholders = generic_sub.placeholders();
+
+    let (access_edges, field_tys, field_id_defs): (Vec<_>, Vec<_>, Vec<_>) = fn_field_reads
+        .iter()
+        .zip(&fn_field_writes)
+        .zip(&fn_mod_ids)
+        .map(|((reads, writes), m)| {
+            let fields = reads.iter().chain(writes).unique().collect::<Vec<_>>();
+            let field_node = |name: &Ident| {
+                let n = fields.iter().position(|f| *f == name).unwrap_or_default();
+                let alias = format_ident!("FieldName{}", n);
+                quote! { <#self_ty as ::typegraph::FieldNode<#m::ids::#alias>> }
+            };
+            let edges = reads
+                .iter()
+                .map(|f| {
+                    let field = field_node(f);
+                    quote! { ::typegraph::TypedEdge<#field::Id, Self::Id, ::typegraph::edge::Reads> }
+                })
+                .chain(writes.iter().map(|f| {
+                    let field = field_node(f);
+                    quote! { ::typegraph::TypedEdge<Self::Id, #field::Id, ::typegraph::edge::Writes> }
+                }))
+                .collect::<Vec<_>>();
+            let tys = fields
+                .iter()
+                .map(|f| {
+                    let field = field_node(f);
+                    quote! { #field::Field }
+                })
+                .collect::<Vec<_>>();
+            let defs = fields
+                .iter()
+                .enumerate()
+                .map(|(n, f)| {
+                    let alias = format_ident!("FieldName{}", n);
+                    let hash: proc_macro2::TokenStream = id::hashed(quote! { #f }.into()).into();
+                    quote! { pub type #alias = #hash; }
+                })
+                .collect::<Vec<_>>();
+
+            (edges, tys, defs)
+        })
+        .multiunzip();
+    let field_tys = field_tys.into_iter().flatten().unique_by(|t| t.to_string());
+
+    let (call_edges, callee_tys, callee_id_defs): (Vec<_>, Vec<_>, Vec<_>) = fn_traced_calls
+        .iter()
+        .zip(&fn_calls)
+        .zip(&fn_mod_ids)
+        .map(|((traced, calls), caller_mod)| {
+            let callees = calls
+                .iter()
+                .enumerate()
+                .filter_map(|(n, path)| call::Callee::new(path, caller_mod, n))
+                .collect::<Vec<_>>();
+            let edges = traced
+                .iter()
+                .filter_map(|name| fn_idents.iter().position(|i| i == name))
+                .map(|j| &fn_mod_ids[j])
+                .map(|callee_mod| quote! { #callee_mod::ids::FnNodeId })
+                .chain(callees.iter().map(|c| c.id.clone()))
+                .map(|callee| quote! { ::typegraph::TypedEdge<Self::Id, #callee, ::typegraph::edge::Invokes> })
+                .collect::<Vec<_>>();
+            let (tys, defs): (Vec<_>, Vec<_>) = callees.into_iter().map(|c| (c.ty, c.ids)).unzip();
+
+            (edges, tys, defs)
+        })
+        .multiunzip();
+    let callee_tys = callee_tys.into_iter().flatten();
+    let fn_name_ids = fn_idents
+        .iter()
+        .map(|name| -> proc_macro2::TokenStream { id::hashed(quote! { #name }.into()).into() })
+        .collect::<Vec<_>>();
+    // Only inherent methods can be looked up by name, trait methods may share names with them.
+    let method_node_impls = fn_mod_ids.iter().map(|m| {
+        if trait_ident.is_none() {
+            quote! {
+                impl #impl_generics ::typegraph::MethodNode<#m::ids::FnName> for #self_ty {
+                    type Id = #m::ids::FnNodeId;
+                }
+            }
+        } else {
+            quote! {}
+        }
+    });
 
     let fn_impl_generics = fn_trait_names
         .iter()
@@ -216,6 +395,34 @@ pub fn impl_macro(attr: TokenStream, mut implementation: syn::ItemImpl) -> Token
         })
         .collect::<Vec<_>>();
 
+    let impl_output_kind = if trait_ident.is_some() {
+        quote! { NodeOutputKind::TraitImplementation }
+    } else {
+        quote! { NodeOutputKind::Implementation }
+    };
+    let (trait_type, trait_edge, trait_node, trait_node_id) = match (
+        &implementation.trait_,
+        &trait_ident,
+        &trait_id,
+    ) {
+        (Some((_, trait_path, _)), Some(trait_ident), Some(trait_id)) => {
+            let trait_node_label = format_ident!("{}Trait{}", NODE_DATA_LABEL, trait_ident);
+
+            (
+                quote! { #mod_id::#trait_node_label },
+                quote! { ::typegraph::TypedEdge<#mod_id::ids::ImplNodeId, #mod_id::ids::TraitNodeId, ::typegraph::edge::Implements>, },
+                trait_node(
+                    &trait_node_label,
+                    trait_ident,
+                    trait_path,
+                    quote! { ids::TraitNodeId },
+                ),
+                quote! { pub type TraitNodeId = #trait_id; },
+            )
+        }
+        _ => (quote! {}, quote! {}, quote! {}, quote! {}),
+    };
+
     #[cfg(feature = "value")]
     let impl_name = implementation
         .trait_
@@ -230,14 +437,43 @@ pub fn impl_macro(attr: TokenStream, mut implementation: syn::ItemImpl) -> Token
                 stringify!(impl #impl_generics #self_ty)
             }
         });
+    // The trait's arguments label the `implements` edge, as `From<A>` and `From<B>` share a node.
+    #[cfg(feature = "value")]
+    let trait_args = implementation
+        .trait_
+        .as_ref()
+        .and_then(|(_, path, _)| path.segments.last())
+        .and_then(generic::arguments)
+        .map(|args| {
+            quote! { (stringify!(#args), <#mod_id::ids::TraitNodeId as ::typegraph::Unsigned>::U32), }
+        });
+    #[cfg(feature = "value")]
+    let associated_meta = quote! {
+        &[#trait_args #((#associated_names, <<#associated_tys as ::typegraph::Typegraph>::Id as ::typegraph::Unsigned>::U32)),*]
+    };
+    #[cfg(feature = "value")]
+    let impl_node_kind = match &implementation.trait_ {
+        Some((_, trait_path, _)) => quote! {
+            ::typegraph::NodeKind::TraitImplementation(
+                #impl_name,
+                &[#(stringify!(#subgraph)),*],
+                stringify!(#trait_path),
+                #associated_meta,
+            )
+        },
+        None => quote! {
+            ::typegraph::NodeKind::Implementation(
+                #impl_name,
+                &[#(stringify!(#subgraph)),*],
+                #associated_meta,
+            )
+        },
+    };
     #[cfg(feature = "value")]
     let value_impl = quote! {
-        impl Value<NodeKind> for #impl_node_label {
-            fn value() -> NodeKind {
-                NodeKind::Implementation(
-                    #impl_name,
-                    &[#(stringify!(#subgraph)),*],
-                )
+        impl ::typegraph::Value<::typegraph::NodeKind> for #mod_id::#impl_node_label {
+            fn value() -> ::typegraph::NodeKind {
+                #impl_node_kind
             }
         }
     };
@@ -263,8 +499,9 @@ pub fn impl_macro(attr: TokenStream, mut implementation: syn::ItemImpl) -> Token
 
     quote! {
         #implementation
+        #placeholders
 
-        pub trait #impl_trait_name #impl_generics {
+        pub trait #impl_trait_name #impl_generics: ::typegraph::ListedImpl<#mod_id::ids::ImplName> {
             type Nodes;
             type Edges;
             type Types;
@@ -276,13 +513,16 @@ pub fn impl_macro(attr: TokenStream, mut implementation: syn::ItemImpl) -> Token
             ];
             type Edges = ::typegraph::merge_sets![
                 ::typegraph::set![
-                    ::typegraph::Edge<<#self_ty as ::typegraph::Typegraph>::Id, #mod_id::ids::ImplNodeId>,
-                    #(::typegraph::Edge<#mod_id::ids::ImplNodeId, #fn_mod_ids::ids::FnNodeId>),*
+                    ::typegraph::TypedEdge<<#self_ty as ::typegraph::Typegraph>::Id, #mod_id::ids::ImplNodeId, ::typegraph::edge::ImplementedBy>,
+                    #trait_edge
+                    #(::typegraph::TypedEdge<#mod_id::ids::ImplNodeId, #fn_mod_ids::ids::FnNodeId, #fn_edge_kinds>),*
                 ],
+                ::typegraph::set![#(::typegraph::TypedEdge<#mod_id::ids::ImplNodeId, <#associated_tys as ::typegraph::Typegraph>::Id, ::typegraph::edge::Associates>),*],
                 #(<#self_ty as #fn_trait_names #fn_impl_generics>::Edges),*
             ];
-            type Types = ::typegraph::list![#(#arg_ret_types),*];
+            type Types = ::typegraph::list![#(#arg_ret_types,)* #(#associated_tys,)* #(#callee_tys,)* #(#field_tys,)* #trait_type];
         }
+        #value_impl
 
         mod #mod_id {
             use typegraph::*;
@@ -290,14 +530,17 @@ pub fn impl_macro(attr: TokenStream, mut implementation: syn::ItemImpl) -> Token
             pub struct #impl_node_label;
             impl NodeOutputData for #impl_node_label {
                 const ID: u32 = <ids::ImplNodeId as Unsigned>::U32;
-                const KIND: NodeOutputKind = NodeOutputKind::Implementation;
+                const KIND: NodeOutputKind = #impl_output_kind;
                 const NAME: &'static str = stringify!(#impl_node_label);
+                const PATH: &'static str = #impl_path;
             }
-            #value_impl
+            #trait_node
 
             pub mod ids {
                 use typegraph::num::*;
                 pub type ImplNodeId = #impl_id;
+                pub type ImplName = #impl_name_id;
+                #trait_node_id
             }
         }
 
@@ -312,9 +555,13 @@ pub fn impl_macro(attr: TokenStream, mut implementation: syn::ItemImpl) -> Token
                 type Node = ::typegraph::NodeOutput<Self::Id, #fn_mod_ids::#fns, #fn_metadata>;
                 type Edges = ::typegraph::merge_sets![
                     ::typegraph::set![#(#ret_edges),*],
-                    ::typegraph::set![#(#arg_edges),*]
+                    ::typegraph::set![#(#arg_edges),*],
+                    ::typegraph::set![#(#call_edges),*],
+                    ::typegraph::set![#(#access_edges),*]
                 ];
             }
+            #method_node_impls
+            #fn_placeholders
 
             #fn_value_impls
 
@@ -326,14 +573,59 @@ pub fn impl_macro(attr: TokenStream, mut implementation: syn::ItemImpl) -> Token
                     const ID: u32 = <ids::FnNodeId as Unsigned>::U32;
                     const KIND: NodeOutputKind = #fn_output_kinds;
                     const NAME: &'static str = stringify!(#fns);
+                    const PATH: &'static str = #fn_paths;
                 }
 
                 pub mod ids {
                     use ::typegraph::num::*;
                     pub type FnNodeId = #fn_ids;
+                    pub type FnName = #fn_name_ids;
+                    #(#callee_id_defs)*
+                    #(#field_id_defs)*
                 }
             }
         )*
     }
     .into()
 }
+
+/// The node of the trait `ident`, named by `path`, for a module that glob-imports `typegraph`.
+/// Every trait node shares the ID derived from [`id::trait_path`], so nodes emitted for the same
+/// trait by different items merge. For the same reason, the node is always its own metadata: the
+/// `meta` of the item emitting it would take over the node of every other item.
+pub fn trait_node(
+    node_label: &Ident,
+    ident: &Ident,
+    path: &syn::Path,
+    node_id: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    #[cfg(feature = "value")]
+    let value_impl = quote! {
+        impl Value<NodeKind> for #node_label {
+            fn value() -> NodeKind {
+                NodeKind::Trait(stringify!(trait #ident), &[], &[])
+            }
+        }
+    };
+    #[cfg(not(feature = "value"))]
+    let value_impl = quote! {};
+    let path = id::trait_path(path);
+
+    quote! {
+        pub struct #node_label;
+        impl NodeOutputData for #node_label {
+            const ID: u32 = <#node_id as Unsigned>::U32;
+            const KIND: NodeOutputKind = NodeOutputKind::Trait;
+            const NAME: &'static str = stringify!(#ident);
+            const PATH: &'static str = #path;
+        }
+        impl Typegraph for #node_label {
+            type Id = #node_id;
+            type Node = #node_label;
+            type Nodes = set![NodeOutput<Self::Id, #node_label, #node_label>];
+            type Edges = set![];
+            type Types = list![];
+        }
+        #value_impl
+    }
+}
@@ -4,24 +4,30 @@ extern crate syn;
 
 use std::collections::HashMap;
 
+use itertools::Itertools;
 use proc_macro::TokenStream;
 use quote::{format_ident, quote, ToTokens};
 use syn::{
-    parse_macro_input, parse_quote, Data, DataEnum, DataStruct, DeriveInput, Fields, Ident, Path,
-    PathArguments, Type, Visibility,
+    parse_macro_input, parse_quote, Data, DataEnum, DataStruct, DataUnion, DeriveInput, Fields,
+    FieldsNamed, Ident, Path, PathArguments, Type, Visibility,
 };
 
+mod access;
+mod alias;
 mod argument;
+mod call;
 mod field;
 mod function;
 mod generic;
 mod id;
 mod implementation;
+mod traits;
 mod variant;
 
-use id::uid;
-
-const MAX_NODE_IDS: u32 = 32768;
+/// Upper bound (exclusive) for node IDs derived from item paths.
+const MAX_NODE_IDS: u32 = 1 << 28;
+/// IDs below this are reserved for the nodes `typegraph` itself provides (primitives, std types).
+const RESERVED_NODE_IDS: u32 = 1 << 16;
 const NODE_DATA_LABEL: &str = "NodeData";
 const HELPER_ATTRIBUTE: &str = "typegraph";
 const IGNORE_ATTRIBUTE: &str = "skip";
@@ -37,6 +43,10 @@ struct Attributes {
     generic: bool,
     #[deluxe(default)]
     generics: Vec<Type>,
+    remote: Option<Path>,
+    path: Option<Path>,
+    #[deluxe(default)]
+    trace_fields: bool,
 }
 
 struct Kind {
@@ -68,8 +78,8 @@ impl Kind {
             ),
             Data::Union(_) => (
                 parse_quote! { NodeOutputKind::Union },
-                parse_quote! { NodeKind::Struct },
-                quote! {},
+                parse_quote! { NodeKind::Union },
+                quote! { union },
             ),
         };
 
@@ -83,35 +93,56 @@ impl Kind {
 
 struct State {
     node_id: proc_macro2::TokenStream,
+    node_path: String,
     node_label: Ident,
     mod_label: Ident,
     enum_variants: Vec<Ident>,
     enum_variant_names: Vec<Ident>,
     enum_variant_ids: Vec<proc_macro2::TokenStream>,
+    enum_variant_paths: Vec<String>,
     enum_variant_tys: Vec<Vec<Type>>,
     enum_variant_trait_names: Vec<Ident>,
     enum_variant_field_edge_labels: Vec<Vec<String>>,
     enum_variant_id_labels: Vec<Ident>,
     fields: HashMap<Type, String>,
+    field_nodes: Vec<(Ident, proc_macro2::TokenStream, String)>,
+    module: Option<Path>,
+    trace_fields: bool,
 }
 
 impl State {
-    fn new(ident: &Ident) -> Self {
+    fn new(
+        ident: &Ident,
+        remote: Option<&Path>,
+        module: Option<&Path>,
+        trace_fields: bool,
+    ) -> Result<Self, TokenStream> {
         let node_label = format_ident!("{}{}", NODE_DATA_LABEL, ident);
+        // Every proxy for the same remote type shares its node.
+        let node_path = match remote {
+            Some(path) => id::remote_path(path),
+            None => id::item_path("type", module, &[ident]),
+        };
+        let node_id = id::node_id(&node_path, ident.span())?;
 
-        Self {
+        Ok(Self {
             mod_label: format_ident!("typegraph_{}", node_label.to_string().to_lowercase()),
             node_label,
-            node_id: uid(),
+            node_id,
+            node_path,
             enum_variants: vec![],
             enum_variant_names: vec![],
             enum_variant_ids: vec![],
+            enum_variant_paths: vec![],
             enum_variant_tys: vec![],
             enum_variant_trait_names: vec![],
             enum_variant_field_edge_labels: vec![],
             enum_variant_id_labels: vec![],
             fields: Default::default(),
-        }
+            field_nodes: vec![],
+            module: module.cloned(),
+            trace_fields,
+        })
     }
 
     fn fields(&self) -> (Vec<String>, Vec<Type>) {
@@ -124,6 +155,19 @@ impl State {
         (field_names, field_tys)
     }
 
+    /// With `trace_fields`, every field, skipped or not, can be the target of an access edge.
+    fn add_field_node(&mut self, ident: &Ident, name: Ident) -> Result<(), TokenStream> {
+        if !self.trace_fields {
+            return Ok(());
+        }
+
+        let path = id::item_path("field", self.module.as_ref(), &[ident, &name]);
+        let id = id::node_id(&path, name.span())?;
+        self.field_nodes.push((name, id, path));
+
+        Ok(())
+    }
+
     fn add_field(&mut self, field: field::State) {
         if let Some(s) = self.fields.get_mut(&field.ty) {
             *s = format!("{s}, {}", field.name);
@@ -132,11 +176,38 @@ impl State {
         }
     }
 
+    fn add_named_fields(
+        &mut self,
+        fields: &mut FieldsNamed,
+        ident: &Ident,
+    ) -> Result<(), TokenStream> {
+        for field in &mut fields.named {
+            let name = field
+                .ident
+                .as_ref()
+                .cloned()
+                .unwrap_or_else(|| format_ident!("unknown"));
+            self.add_field_node(ident, name.clone())?;
+
+            match field::State::try_from_field(field, name)? {
+                Outcome::Skip => {
+                    continue;
+                }
+                Outcome::Connect(tf) => {
+                    self.add_field(tf);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     fn add_variant(&mut self, tv: variant::State) {
         let (field_names, field_tys) = tv.fields();
         self.enum_variants.push(tv.label);
         self.enum_variant_names.push(tv.name);
         self.enum_variant_ids.push(tv.id);
+        self.enum_variant_paths.push(tv.path);
         self.enum_variant_trait_names.push(tv.trait_name);
         self.enum_variant_tys.push(field_tys);
         self.enum_variant_field_edge_labels.push(field_names);
@@ -146,9 +217,12 @@ impl State {
     fn try_from_data(
         data: &mut syn::Data,
         ident: &Ident,
+        remote: Option<&Path>,
+        module: Option<&Path>,
+        trace_fields: bool,
         is_generic: bool,
     ) -> Result<Outcome<Self>, TokenStream> {
-        let mut state = Self::new(ident);
+        let mut state = Self::new(ident, remote, module, trace_fields)?;
 
         match data {
             Data::Struct(DataStruct { fields, .. }) => match fields {
@@ -162,22 +236,7 @@ impl State {
                         .into());
                     }
 
-                    for field in &mut fields.named {
-                        let name = field
-                            .ident
-                            .as_ref()
-                            .cloned()
-                            .unwrap_or_else(|| format_ident!("unknown"));
-
-                        match field::State::try_from_field(field, name)? {
-                            Outcome::Skip => {
-                                continue;
-                            }
-                            Outcome::Connect(tf) => {
-                                state.add_field(tf);
-                            }
-                        }
-                    }
+                    state.add_named_fields(fields, ident)?;
                 }
                 Fields::Unnamed(fields) => {
                     if is_generic {
@@ -191,6 +250,7 @@ impl State {
 
                     for (i, field) in fields.unnamed.iter_mut().enumerate() {
                         let name = format_ident!("_{i}");
+                        state.add_field_node(ident, name.clone())?;
 
                         match field::State::try_from_field(field, name)? {
                             Outcome::Skip => {
@@ -214,7 +274,12 @@ impl State {
                     .into());
                 }
                 for variant in variants.iter_mut() {
-                    match variant::State::try_from_variant(variant, &state.node_label)? {
+                    match variant::State::try_from_variant(
+                        variant,
+                        ident,
+                        &state.node_label,
+                        state.module.as_ref(),
+                    )? {
                         Outcome::Skip => {
                             continue;
                         }
@@ -224,7 +289,18 @@ impl State {
                     }
                 }
             }
-            _ => {}
+            Data::Union(DataUnion { fields, .. }) => {
+                if is_generic {
+                    return Err(syn::Error::new_spanned(
+                        fields,
+                        "Only unit structs may be used for typegraph generics.",
+                    )
+                    .to_compile_error()
+                    .into());
+                }
+
+                state.add_named_fields(fields, ident)?;
+            }
         }
 
         Ok(Outcome::Connect(state))
@@ -270,6 +346,9 @@ pub fn typegraph_derive(input: TokenStream) -> TokenStream {
         cluster,
         generic,
         generics,
+        remote,
+        path: module,
+        trace_fields,
     } = match deluxe::extract_attributes(&mut input) {
         Ok(desc) => desc,
         Err(e) => return e.into_compile_error().into(),
@@ -293,11 +372,39 @@ pub fn typegraph_derive(input: TokenStream) -> TokenStream {
         .to_compile_error()
         .into();
     }
-    let mut generic_sub = generic::Substitution::new(generics, &input.generics);
+    if let (true, Some(remote)) = (generic, &remote) {
+        return syn::Error::new_spanned(remote, "Typegraph generics cannot be remote types.")
+            .to_compile_error()
+            .into();
+    }
+    let mut generic_sub = match generic::Substitution::new(
+        generics,
+        &input.generics,
+        &input.ident,
+        &id::item_path("type", module.as_ref(), &[&input.ident]),
+    ) {
+        Ok(sub) => sub,
+        Err(tt) => return tt,
+    };
 
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let impl_name_ids = implementations
+        .iter()
+        .filter_map(|p| p.segments.last().map(|seg| &seg.ident))
+        .map(|name| -> proc_macro2::TokenStream { id::hashed(quote! { #name }.into()).into() })
+        .collect::<Vec<_>>();
+    let impl_name_labels = (0..impl_name_ids.len())
+        .map(|i| format_ident!("ImplName{}", i))
+        .collect::<Vec<_>>();
     let Implementations(impl_paths) = Implementations::new(implementations, &input.ident);
-    let state = match State::try_from_data(&mut input.data, &input.ident, generic) {
+    let state = match State::try_from_data(
+        &mut input.data,
+        &input.ident,
+        remote.as_ref(),
+        module.as_ref(),
+        trace_fields,
+        generic,
+    ) {
         Ok(Outcome::Connect(s)) => s,
         Ok(Outcome::Skip) => unreachable!(),
         Err(tt) => {
@@ -310,7 +417,7 @@ pub fn typegraph_derive(input: TokenStream) -> TokenStream {
         Some(ty) => quote! { #ty },
         None => quote! { #mod_label::#node_label },
     };
-    let var_meta: Vec<proc_macro2::TokenStream> = match meta {
+    let var_meta: Vec<proc_macro2::TokenStream> = match &meta {
         Some(ty) => vec![quote! { #ty }],
         None => state.enum_variants.iter().map(|v| quote! { #v }).collect(),
     };
@@ -330,7 +437,7 @@ pub fn typegraph_derive(input: TokenStream) -> TokenStream {
         .map(|ty| generic_sub.substitute(ty))
         .collect::<Vec<_>>();
     let field_edge_ids_or_stub = field_tys.iter().map(|t| {
-        quote! { ::typegraph::Edge<Self::Id, <#t as ::typegraph::Typegraph>::Id> }
+        quote! { ::typegraph::TypedEdge<Self::Id, <#t as ::typegraph::Typegraph>::Id, ::typegraph::edge::PropertyOf> }
     });
 
     let (variant_tys, variant_field_edge_labels) =
@@ -342,21 +449,47 @@ pub fn typegraph_derive(input: TokenStream) -> TokenStream {
     let variant_field_edge_ids_or_stub = variant_tys.iter().map(|v| {
         v.iter()
             .map(|t| {
-                quote! { ::typegraph::Edge<Self::Id, <#t as ::typegraph::Typegraph>::Id> }
+                quote! { ::typegraph::TypedEdge<Self::Id, <#t as ::typegraph::Typegraph>::Id, ::typegraph::edge::PropertyOf> }
             })
             .collect::<Vec<_>>()
     });
 
     let State {
         node_id,
+        node_path,
         enum_variants,
         enum_variant_trait_names,
         enum_variant_ids,
+        enum_variant_paths,
         enum_variant_names,
         enum_variant_id_labels,
+        field_nodes,
         ..
     } = state;
 
+    let (field_node_names, field_node_ids, field_node_paths): (Vec<_>, Vec<_>, Vec<_>) =
+        field_nodes.into_iter().multiunzip();
+    let field_node_labels = (0..field_node_names.len())
+        .map(|i| format_ident!("Field{}", i))
+        .collect::<Vec<_>>();
+    let field_node_id_labels = (0..field_node_names.len())
+        .map(|i| format_ident!("Field{}Id", i))
+        .collect::<Vec<_>>();
+    let field_node_name_labels = (0..field_node_names.len())
+        .map(|i| format_ident!("FieldName{}", i))
+        .collect::<Vec<_>>();
+    let field_node_name_ids = field_node_names
+        .iter()
+        .map(|name| -> proc_macro2::TokenStream { id::hashed(quote! { #name }.into()).into() })
+        .collect::<Vec<_>>();
+    let field_node_metadata = field_node_labels
+        .iter()
+        .map(|label| match &meta {
+            Some(ty) => quote! { #ty },
+            None => quote! { #mod_label::#label },
+        })
+        .collect::<Vec<_>>();
+
     let Kind {
         node_output_kind,
         node_kind,
@@ -380,9 +513,17 @@ pub fn typegraph_derive(input: TokenStream) -> TokenStream {
             )
         }
     } else {
+        // A remote proxy is labeled with the foreign type it stands in for.
+        let label = match &remote {
+            Some(path) => {
+                let name = format!("{prefix} {}", path.to_token_stream()).replace(" :: ", "::");
+                quote! { concat!(#name, stringify!(#ty_generics)) }
+            }
+            None => quote! { stringify!(#prefix #ident #ty_generics) },
+        };
         quote! {
             ::typegraph::#node_kind(
-                stringify!(#prefix #ident #ty_generics),
+                #label,
                 &[#(stringify!(#subgraph)),*],
                 &[#((#field_edge_labels, <<#field_tys as ::typegraph::Typegraph>::Id as ::typegraph::Unsigned>::U32)),*]
             )
@@ -421,7 +562,29 @@ pub fn typegraph_derive(input: TokenStream) -> TokenStream {
     #[cfg(not(feature = "value"))]
     let value_impl = quote! {};
 
+    #[cfg(feature = "value")]
+    let field_node_cluster = quote! { &[#(stringify!(#subgraph)),*] };
+    #[cfg(feature = "value")]
+    let field_node_value_impls = quote! {
+        #(
+            impl ::typegraph::Value<::typegraph::NodeKind> for #mod_label::#field_node_labels {
+                fn value() -> ::typegraph::NodeKind {
+                    ::typegraph::NodeKind::Field(
+                        stringify!(#field_node_names),
+                        #field_node_cluster,
+                    )
+                }
+            }
+        )*
+    };
+    #[cfg(not(feature = "value"))]
+    let field_node_value_impls = quote! {};
+
+    let placeholders = generic_sub.placeholders();
+
     quote! {
+        #placeholders
+
         impl #impl_generics ::typegraph::Typegraph for #ident #ty_generics #where_clause {
             type Id = #mod_label::ids::NodeId;
             type Node = #mod_label::#node_label;
@@ -434,7 +597,7 @@ pub fn typegraph_derive(input: TokenStream) -> TokenStream {
             ];
             type Edges = ::typegraph::merge_sets![
                 ::typegraph::set![#(#field_edge_ids_or_stub),*],
-                ::typegraph::set![#(::typegraph::Edge<Self::Id, #mod_label::ids::#enum_variant_id_labels>),*],
+                ::typegraph::set![#(::typegraph::TypedEdge<Self::Id, #mod_label::ids::#enum_variant_id_labels, ::typegraph::edge::VariantOf>),*],
                 ::typegraph::merge_sets![#(<#enum_variants as #enum_variant_trait_names>::Edges),*],
                 #(<Self as #impl_paths #implementation_generics>::Edges),*
             ];
@@ -445,6 +608,24 @@ pub fn typegraph_derive(input: TokenStream) -> TokenStream {
             ];
         }
 
+        #(
+            impl #impl_generics ::typegraph::ListedImpl<#mod_label::ids::#impl_name_labels> for #ident #ty_generics #where_clause {}
+        )*
+
+        #(
+            impl #impl_generics ::typegraph::FieldNode<#mod_label::ids::#field_node_name_labels> for #ident #ty_generics #where_clause {
+                type Id = #mod_label::ids::#field_node_id_labels;
+                type Field = #mod_label::#field_node_labels;
+            }
+            impl ::typegraph::Typegraph for #mod_label::#field_node_labels {
+                type Id = #mod_label::ids::#field_node_id_labels;
+                type Node = Self;
+                type Nodes = ::typegraph::set![::typegraph::NodeOutput<Self::Id, Self, #field_node_metadata>];
+                type Edges = ::typegraph::set![::typegraph::TypedEdge<#mod_label::ids::NodeId, Self::Id, ::typegraph::edge::FieldOf>];
+                type Types = ::typegraph::list![];
+            }
+        )*
+
         #(
             pub trait #enum_variant_trait_names {
                 type Id;
@@ -457,6 +638,7 @@ pub fn typegraph_derive(input: TokenStream) -> TokenStream {
                 const ID: u32 = <<Self as #enum_variant_trait_names>::Id as ::typegraph::Unsigned>::U32;
                 const KIND: ::typegraph::NodeOutputKind = ::typegraph::NodeOutputKind::Variant;
                 const NAME: &'static str = stringify!(#enum_variant_names);
+                const PATH: &'static str = #enum_variant_paths;
             }
             #enum_variant_value_impls
             impl #enum_variant_trait_names for #enum_variants {
@@ -476,8 +658,19 @@ pub fn typegraph_derive(input: TokenStream) -> TokenStream {
                 const ID: u32 = <ids::NodeId as Unsigned>::U32;
                 const KIND: NodeOutputKind = #node_output_kind;
                 const NAME: &'static str = stringify!(#node_label);
+                const PATH: &'static str = #node_path;
             }
 
+            #(
+                pub struct #field_node_labels;
+                impl NodeOutputData for #field_node_labels {
+                    const ID: u32 = <ids::#field_node_id_labels as Unsigned>::U32;
+                    const KIND: NodeOutputKind = NodeOutputKind::Field;
+                    const NAME: &'static str = stringify!(#field_node_names);
+                    const PATH: &'static str = #field_node_paths;
+                }
+            )*
+
             pub mod ids {
                 use typegraph::num::*;
 
@@ -485,10 +678,16 @@ pub fn typegraph_derive(input: TokenStream) -> TokenStream {
                 #(
                     pub type #enum_variant_id_labels = #enum_variant_ids; 
                 )*
+                #(
+                    pub type #field_node_id_labels = #field_node_ids;
+                    pub type #field_node_name_labels = #field_node_name_ids;
+                )*
+                #(pub type #impl_name_labels = #impl_name_ids;)*
             }
         }
 
         #value_impl
+        #field_node_value_impls
     }
     .into()
 }
@@ -498,76 +697,57 @@ pub fn typegraph(attr: TokenStream, item: TokenStream) -> TokenStream {
     let input = parse_macro_input!(item as syn::Item);
     match input {
         syn::Item::Impl(x) => implementation::impl_macro(attr, x),
-        syn::Item::Enum(mut x) => {
-            #[cfg(not(feature = "inert"))]
-            {
-                x.vis = Visibility::Public(syn::token::Pub {
-                    span: x.ident.span(),
-                });
-            }
-
-            let attr: proc_macro2::TokenStream = attr.into();
-            let attr: syn::Attribute = parse_quote! { #[typegraph(#attr)] };
-            let y = strip_attributes(syn::Item::Enum(x.clone()));
-            x.attrs.push(attr);
-            let x = syn::Item::Enum(x);
-
-            let derived: proc_macro2::TokenStream =
-                typegraph_derive(x.into_token_stream().into()).into();
-            let tokens: proc_macro2::TokenStream = y.into_token_stream();
-            quote! {
-                #tokens
-                #derived
-            }
-            .into()
+        item @ (syn::Item::Enum(_) | syn::Item::Struct(_) | syn::Item::Union(_)) => {
+            type_macro(attr, item)
         }
-        syn::Item::Struct(mut x) => {
-            #[cfg(not(feature = "inert"))]
-            {
-                x.vis = Visibility::Public(syn::token::Pub {
-                    span: x.ident.span(),
-                });
-            }
-
-            let attr: proc_macro2::TokenStream = attr.into();
-            let attr: syn::Attribute = parse_quote! { #[typegraph(#attr)] };
-            let y = strip_attributes(syn::Item::Struct(x.clone()));
-            x.attrs.push(attr);
-            let x = syn::Item::Struct(x);
-
-            let derived: proc_macro2::TokenStream =
-                typegraph_derive(x.into_token_stream().into()).into();
-            let tokens: proc_macro2::TokenStream = y.into_token_stream();
-            quote! {
-                #tokens
-                #derived
-            }
-            .into()
-        }
-        syn::Item::Fn(x) => syn::Item::Fn(x).into_token_stream().into(),
+        syn::Item::Fn(x) => function::fn_macro(attr, x),
+        syn::Item::Trait(x) => traits::trait_macro(attr, x),
+        syn::Item::Type(x) => alias::alias_macro(attr, x),
         item => syn::Error::new_spanned(
             item,
-            "This macro can only be applied to structs, enums, and implementations.",
+            "This macro can only be applied to structs, enums, unions, functions, traits, type aliases, and implementations.",
         )
         .to_compile_error()
         .into(),
     }
 }
 
+/// Expands the attribute on an enum, struct or union through the derive.
+fn type_macro(
+    attr: TokenStream,
+    #[cfg_attr(feature = "inert", allow(unused_mut))] mut item: syn::Item,
+) -> TokenStream {
+    #[cfg(not(feature = "inert"))]
+    if let syn::Item::Enum(syn::ItemEnum { vis, ident, .. })
+    | syn::Item::Struct(syn::ItemStruct { vis, ident, .. })
+    | syn::Item::Union(syn::ItemUnion { vis, ident, .. }) = &mut item
+    {
+        *vis = Visibility::Public(syn::token::Pub { span: ident.span() });
+    }
+
+    let attr: proc_macro2::TokenStream = attr.into();
+    let tokens = strip_attributes(item.clone());
+    let derived: proc_macro2::TokenStream =
+        typegraph_derive(quote! { #[typegraph(#attr)] #item }.into()).into();
+    quote! {
+        #tokens
+        #derived
+    }
+    .into()
+}
+
 fn strip_attributes(item: syn::Item) -> proc_macro2::TokenStream {
     match item {
         syn::Item::Struct(mut x) => {
-            x.fields.iter_mut().for_each(|f| {
-                f.attrs = f
-                    .attrs
-                    .iter()
-                    .filter(|a| !a.path().is_ident(HELPER_ATTRIBUTE))
-                    .cloned()
-                    .collect()
-            });
+            strip_field_attributes(x.fields.iter_mut());
             syn::Item::Struct(x).into_token_stream()
         }
 
+        syn::Item::Union(mut x) => {
+            strip_field_attributes(x.fields.named.iter_mut());
+            syn::Item::Union(x).into_token_stream()
+        }
+
         syn::Item::Enum(mut x) => {
             x.variants.iter_mut().for_each(|variant| {
                 variant.attrs = variant
@@ -577,29 +757,7 @@ fn strip_attributes(item: syn::Item) -> proc_macro2::TokenStream {
                     .cloned()
                     .collect();
 
-                match &mut variant.fields {
-                    Fields::Named(f) => {
-                        f.named.iter_mut().for_each(|f| {
-                            f.attrs = f
-                                .attrs
-                                .iter()
-                                .filter(|a| !a.path().is_ident(HELPER_ATTRIBUTE))
-                                .cloned()
-                                .collect()
-                        });
-                    }
-                    Fields::Unnamed(f) => {
-                        f.unnamed.iter_mut().for_each(|f| {
-                            f.attrs = f
-                                .attrs
-                                .iter()
-                                .filter(|a| !a.path().is_ident(HELPER_ATTRIBUTE))
-                                .cloned()
-                                .collect()
-                        });
-                    }
-                    _ => {}
-                }
+                strip_field_attributes(variant.fields.iter_mut());
             });
 
             syn::Item::Enum(x).into_token_stream()
@@ -608,3 +766,14 @@ fn strip_attributes(item: syn::Item) -> proc_macro2::TokenStream {
         x => x.into_token_stream(),
     }
 }
+
+fn strip_field_attributes<'a>(fields: impl Iterator<Item = &'a mut syn::Field>) {
+    fields.for_each(|f| {
+        f.attrs = f
+            .attrs
+            .iter()
+            .filter(|a| !a.path().is_ident(HELPER_ATTRIBUTE))
+            .cloned()
+            .collect()
+    });
+}
@@ -0,0 +1,320 @@
+use itertools::Itertools;
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_quote, GenericParam, Ident, TraitItem, Type, TypeParam};
+
+use crate::{argument, call, function, generic, id, implementation, Outcome, NODE_DATA_LABEL};
+
+#[derive(deluxe::ParseMetaItem, Default)]
+struct Attributes {
+    meta: Option<Type>,
+    #[cfg_attr(not(feature = "value"), allow(dead_code))]
+    cluster: Option<syn::Path>,
+    path: Option<syn::Path>,
+}
+
+/// The marker type generated for the trait `ident`, e.g. `CarnivoreTrait` for `Carnivore`.
+pub fn marker_name(ident: &Ident) -> Ident {
+    format_ident!("{}Trait", ident)
+}
+
+pub fn trait_macro(attr: TokenStream, mut item: syn::ItemTrait) -> TokenStream {
+    let Attributes {
+        meta,
+        #[cfg(feature = "value")]
+        cluster,
+        path: module,
+        ..
+    } = match deluxe::parse(attr) {
+        Ok(desc) => desc,
+        Err(e) => return e.into_compile_error().into(),
+    };
+
+    let ident = item.ident.clone();
+    let marker = marker_name(&ident);
+    let marker_ty: Type = parse_quote! { #marker };
+    let path = id::trait_path(&ident.clone().into());
+    let trait_id = match id::node_id(&path, ident.span()) {
+        Ok(trait_id) => trait_id,
+        Err(tt) => return tt,
+    };
+
+    // Associated types get placeholders like the trait's own parameters do, substituted for
+    // `Self::Food` rather than for every path ending in `Food`.
+    let mut generics = item.generics.clone();
+    for trait_item in &item.items {
+        if let TraitItem::Type(t) = trait_item {
+            generics.params.push(GenericParam::Type(TypeParam {
+                attrs: vec![],
+                ident: t.ident.clone(),
+                colon_token: t.colon_token,
+                bounds: t.bounds.clone(),
+                eq_token: None,
+                default: None,
+            }));
+        }
+    }
+    let associated_idents = item.items.iter().filter_map(|trait_item| match trait_item {
+        TraitItem::Type(t) => Some(&t.ident),
+        _ => None,
+    });
+    let mut generic_sub = match generic::Substitution::new(vec![], &generics, &marker, &path) {
+        Ok(sub) => sub.with_associated(associated_idents),
+        Err(tt) => return tt,
+    };
+
+    let mut fns = vec![];
+    let mut provided = vec![];
+    let mut associated: Vec<(Type, String)> = vec![];
+    for trait_item in &mut item.items {
+        let (ty, name) = match trait_item {
+            TraitItem::Fn(f) => {
+                match function::State::try_from_trait_fn(f, &marker_ty, &ident, module.as_ref()) {
+                    Ok(Outcome::Skip) => {}
+                    Ok(Outcome::Connect(s)) => {
+                        fns.push(s);
+                        provided.push(f.default.is_some());
+                    }
+                    Err(tt) => return tt,
+                }
+                continue;
+            }
+            TraitItem::Type(t) => {
+                let placeholder = &t.ident;
+                (
+                    parse_quote! { Self::#placeholder },
+                    format!("type {}", t.ident),
+                )
+            }
+            TraitItem::Const(c) if argument::is_supported(&c.ty) => {
+                (c.ty.clone(), format!("const {}", c.ident))
+            }
+            _ => continue,
+        };
+        let ty = generic_sub.substitute(ty);
+        if let Some((_, s)) = associated.iter_mut().find(|(t, _)| *t == ty) {
+            *s = format!("{s}, {name}");
+        } else {
+            associated.push((ty, name));
+        }
+    }
+
+    if cfg!(feature = "inert") {
+        return quote! { #item }.into();
+    }
+
+    let mod_id = format_ident!("typegraph_nodetrait_{}", ident.to_string().to_lowercase());
+    let node_label = format_ident!("{}Trait{}", NODE_DATA_LABEL, ident);
+    let metadata = match &meta {
+        Some(ty) => quote! { #ty },
+        None => quote! { #mod_id::#node_label },
+    };
+    let supertraits = generic::bound_traits(&item.supertraits);
+    let super_node_labels = supertraits
+        .iter()
+        .map(|(t, _, _)| format_ident!("{}Trait{}", NODE_DATA_LABEL, t))
+        .collect::<Vec<_>>();
+    let super_id_labels = supertraits
+        .iter()
+        .map(|(t, _, _)| format_ident!("Super{}Id", t))
+        .collect::<Vec<_>>();
+    let super_ids = match supertraits
+        .iter()
+        .map(|(t, path, _)| id::node_id(&id::trait_path(path), t.span()))
+        .collect::<Result<Vec<_>, _>>()
+    {
+        Ok(ids) => ids,
+        Err(tt) => return tt,
+    };
+    let super_nodes = supertraits
+        .iter()
+        .zip(&super_node_labels)
+        .zip(&super_id_labels)
+        .map(|(((t, path, _), label), id_label)| {
+            implementation::trait_node(label, t, path, quote! { ids::#id_label })
+        })
+        .collect::<Vec<_>>();
+
+    let associated_tys = associated
+        .iter()
+        .map(|(ty, _)| ty.clone())
+        .collect::<Vec<_>>();
+    let placeholders = generic_sub.placeholders();
+
+    let fn_mod_ids = fns.iter().map(|f| f.mod_id.clone()).collect::<Vec<_>>();
+    let fn_nodes = fns.iter().map(|f| f.fns_name.clone()).collect::<Vec<_>>();
+    let fn_ids = fns.iter().map(|f| f.id.clone()).collect::<Vec<_>>();
+    let fn_paths = fns.iter().map(|f| f.path.clone()).collect::<Vec<_>>();
+    let fn_output_kinds = fns
+        .iter()
+        .map(|f| f.output_kind.clone())
+        .collect::<Vec<_>>();
+    let fn_edge_kinds = fns.iter().map(|f| f.edge_kind.clone()).collect::<Vec<_>>();
+    let fn_placeholders = fns
+        .iter()
+        .map(|f| f.placeholders.clone())
+        .collect::<Vec<_>>();
+    let fn_metadata = fn_mod_ids
+        .iter()
+        .zip(&fn_nodes)
+        .map(|(m, f)| match &meta {
+            Some(ty) => quote! { #ty },
+            None => quote! { #m::#f },
+        })
+        .collect::<Vec<_>>();
+    let arg_types = fns
+        .iter()
+        .map(|f| generic_sub.substitute_all(f.arg_types.clone()))
+        .collect::<Vec<_>>();
+    let return_types = fns
+        .iter()
+        .map(|f| {
+            f.return_type
+                .clone()
+                .map(|ty| generic_sub.substitute(ty))
+                .into_iter()
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let (callee_ids, callee_tys, callee_id_defs): (Vec<_>, Vec<_>, Vec<_>) = fns
+        .iter()
+        .map(|f| {
+            f.calls
+                .iter()
+                .enumerate()
+                .filter_map(|(n, path)| call::Callee::new(path, &f.mod_id, n))
+                .map(|c| (c.id, c.ty, c.ids))
+                .multiunzip::<(Vec<_>, Vec<_>, Vec<_>)>()
+        })
+        .multiunzip();
+    let types = arg_types
+        .iter()
+        .flatten()
+        .chain(return_types.iter().flatten())
+        .chain(&associated_tys)
+        .cloned()
+        .unique()
+        .collect::<Vec<_>>();
+    let callee_tys = callee_tys.into_iter().flatten();
+
+    #[cfg(feature = "value")]
+    let value_impls = {
+        let subgraph = cluster
+            .into_iter()
+            .flat_map(|s| s.segments.into_iter().map(|p| p.ident))
+            .collect::<Vec<_>>();
+        let (super_labels, super_label_ids): (Vec<_>, Vec<_>) = supertraits
+            .iter()
+            .zip(&super_id_labels)
+            .filter_map(|((_, _, args), id_label)| args.as_ref().map(|a| (a, id_label)))
+            .unzip();
+        let associated_names = associated.iter().map(|(_, name)| name);
+        let fn_provisions = provided
+            .iter()
+            .map(|p| if *p { "provided" } else { "required" });
+        let fn_names = fns.iter().map(|f| &f.name);
+        let fn_kinds = fns.iter().map(|f| &f.kind);
+        let arg_names = fns.iter().map(|f| &f.arg_names);
+        let fn_subgraphs = fns.iter().map(|_| &subgraph);
+        quote! {
+            impl ::typegraph::Value<::typegraph::NodeKind> for #mod_id::#node_label {
+                fn value() -> ::typegraph::NodeKind {
+                    ::typegraph::NodeKind::Trait(
+                        stringify!(trait #ident),
+                        &[#(stringify!(#subgraph)),*],
+                        &[
+                            #((stringify!(#super_labels), <#mod_id::ids::#super_label_ids as ::typegraph::Unsigned>::U32),)*
+                            #((#associated_names, <<#associated_tys as ::typegraph::Typegraph>::Id as ::typegraph::Unsigned>::U32),)*
+                            #((#fn_provisions, <#fn_mod_ids::ids::FnNodeId as ::typegraph::Unsigned>::U32)),*
+                        ]
+                    )
+                }
+            }
+            #(
+                impl ::typegraph::Value<::typegraph::NodeKind> for #fn_mod_ids::#fn_nodes {
+                    fn value() -> ::typegraph::NodeKind {
+                        ::typegraph::#fn_kinds(
+                            stringify!(#fn_names),
+                            &[#(stringify!(#fn_subgraphs)),*],
+                            &[#((stringify!(#arg_names), <<#arg_types as ::typegraph::Typegraph>::Id as ::typegraph::Unsigned>::U32)),*]
+                        )
+                    }
+                }
+            )*
+        }
+    };
+    #[cfg(not(feature = "value"))]
+    let value_impls = quote! {};
+
+    let vis = &item.vis;
+    quote! {
+        #item
+        #placeholders
+        #(#fn_placeholders)*
+
+        #vis struct #marker;
+        impl ::typegraph::Typegraph for #marker {
+            type Id = #mod_id::ids::TraitNodeId;
+            type Node = #mod_id::#node_label;
+            type Nodes = ::typegraph::set![
+                ::typegraph::NodeOutput<Self::Id, #mod_id::#node_label, #metadata>,
+                #(::typegraph::NodeOutput<#fn_mod_ids::ids::FnNodeId, #fn_mod_ids::#fn_nodes, #fn_metadata>),*
+            ];
+            type Edges = ::typegraph::merge_sets![
+                ::typegraph::set![#(::typegraph::TypedEdge<Self::Id, #mod_id::ids::#super_id_labels, ::typegraph::edge::Bound>),*],
+                ::typegraph::set![#(::typegraph::TypedEdge<Self::Id, <#associated_tys as ::typegraph::Typegraph>::Id, ::typegraph::edge::Associates>),*],
+                ::typegraph::set![#(::typegraph::TypedEdge<Self::Id, #fn_mod_ids::ids::FnNodeId, #fn_edge_kinds>),*],
+                #(
+                    ::typegraph::set![
+                        #(::typegraph::TypedEdge<#fn_mod_ids::ids::FnNodeId, <#return_types as ::typegraph::Typegraph>::Id, ::typegraph::edge::Provides>,)*
+                        #(::typegraph::TypedEdge<<#arg_types as ::typegraph::Typegraph>::Id, #fn_mod_ids::ids::FnNodeId, ::typegraph::edge::ArgumentOf>,)*
+                        #(::typegraph::TypedEdge<#fn_mod_ids::ids::FnNodeId, #callee_ids, ::typegraph::edge::Invokes>),*
+                    ]
+                ),*
+            ];
+            type Types = ::typegraph::list![#(#mod_id::#super_node_labels,)* #(#types,)* #(#callee_tys),*];
+        }
+
+        #value_impls
+
+        mod #mod_id {
+            use typegraph::*;
+
+            pub struct #node_label;
+            impl NodeOutputData for #node_label {
+                const ID: u32 = <ids::TraitNodeId as Unsigned>::U32;
+                const KIND: NodeOutputKind = NodeOutputKind::Trait;
+                const NAME: &'static str = stringify!(#ident);
+                const PATH: &'static str = #path;
+            }
+            #(#super_nodes)*
+
+            pub mod ids {
+                use typegraph::num::*;
+                pub type TraitNodeId = #trait_id;
+                #(pub type #super_id_labels = #super_ids;)*
+            }
+        }
+
+        #(
+            mod #fn_mod_ids {
+                use ::typegraph::*;
+
+                pub struct #fn_nodes;
+                impl NodeOutputData for #fn_nodes {
+                    const ID: u32 = <ids::FnNodeId as Unsigned>::U32;
+                    const KIND: NodeOutputKind = #fn_output_kinds;
+                    const NAME: &'static str = stringify!(#fn_nodes);
+                    const PATH: &'static str = #fn_paths;
+                }
+
+                pub mod ids {
+                    use ::typegraph::num::*;
+                    pub type FnNodeId = #fn_ids;
+                    #(#callee_id_defs)*
+                }
+            }
+        )*
+    }
+    .into()
+}
@@ -4,10 +4,11 @@ use proc_macro::TokenStream;
 use quote::format_ident;
 use syn::{Fields, Ident, Type};
 
-use crate::{field, uid, Outcome, IGNORE_ATTRIBUTE};
+use crate::{field, id, Outcome, IGNORE_ATTRIBUTE};
 
 pub struct State {
     pub id: proc_macro2::TokenStream,
+    pub path: String,
     pub name: Ident,
     pub trait_name: Ident,
     pub label: Ident,
@@ -16,18 +17,25 @@ pub struct State {
 }
 
 impl State {
-    fn new(name: &Ident, node_label: &Ident) -> Self {
-        let id = uid();
+    fn new(
+        name: &Ident,
+        ident: &Ident,
+        node_label: &Ident,
+        module: Option<&syn::Path>,
+    ) -> Result<Self, TokenStream> {
+        let path = id::item_path("variant", module, &[ident, name]);
+        let id = id::node_id(&path, name.span())?;
         let label = format_ident!("{}Variant{}", node_label, name);
 
-        Self {
+        Ok(Self {
             id_label: format_ident!("{}Id", label),
             name: name.clone(),
             trait_name: format_ident!("{}Node", label),
             id,
+            path,
             label,
             fields: Default::default(),
-        }
+        })
     }
 
     pub fn fields(&self) -> (Vec<String>, Vec<Type>) {
@@ -47,7 +55,9 @@ impl State {
 
     pub fn try_from_variant(
         variant: &mut syn::Variant,
+        ident: &Ident,
         node_label: &Ident,
+        module: Option<&syn::Path>,
     ) -> Result<Outcome<Self>, TokenStream> {
         for attr in &variant.attrs {
             if attr
@@ -59,7 +69,7 @@ impl State {
             }
         }
 
-        let mut tv = Self::new(&variant.ident, node_label);
+        let mut tv = Self::new(&variant.ident, ident, node_label, module)?;
 
         match &mut variant.fields {
             Fields::Named(fields) => {
@@ -10,23 +10,9 @@ use tyranosaurs::TyranosaurusRex;
 #[typegraph(generic)]
 pub struct _A;
 #[typegraph(generic)]
-pub struct _B;
-#[typegraph(generic)]
-pub struct _C;
-#[typegraph(generic)]
 pub struct _T;
 #[typegraph(generic)]
 pub struct _U;
-#[typegraph(generic)]
-pub struct _D;
-#[typegraph(generic)]
-pub struct _I;
-#[typegraph(generic)]
-pub struct _N;
-#[typegraph(generic)]
-pub struct _O;
-#[typegraph(generic)]
-pub struct _S;
 
 #[typegraph]
 pub struct Age<A> {
@@ -35,6 +21,7 @@ pub struct Age<A> {
     size: u8,
 }
 
+#[typegraph]
 pub trait Carnivore {
     type Food;
     fn eat(&mut self, food: Self::Food);
@@ -55,7 +42,6 @@ pub mod tyranosaurs {
 
     #[typegraph(B)]
     impl TyranosaurusRex {
-        #[typegraph(generics = [_A, _B])]
         fn gogogog<A, B>(&self, name: Option<String>, age: Age<A>, birthday: B) -> Option<B> {
             todo!()
         }
@@ -127,7 +113,6 @@ pub mod raptors {
             PackMember(self)
         }
 
-        #[typegraph(skip_ret)]
         pub fn into_pack(self) -> [Self; 4] {
             [self.clone(), self.clone(), self.clone(), self]
         }
@@ -198,16 +183,14 @@ pub enum Dinos {
     Triceratops(Triceratops<u8, u8, u8>),
 }
 
-#[allow(clippy::type_complexity)]
 #[typegraph(id = B)]
 impl Dinos {
-    #[typegraph(generics = [_D, _I, _N, _O, _S])]
     fn epic<D, I, N, O, S>(
         d: PhantomData<D>,
         i: Option<I>,
         n: Arc<Mutex<N>>,
         o: Result<O, S>,
-    ) -> (D, (I, (N, (O, S)))) {
+    ) -> (D, I, N, O, S) {
         todo!()
     }
 }
@@ -227,13 +210,24 @@ impl<T, U> Epic<T, U> for Dinos {
     }
 }
 
-#[typegraph(generics = [_A, _B, _C])]
+#[typegraph]
 struct Triceratops<A, B, C> {
     a: Option<A>,
     b: Option<B>,
     c: Option<C>,
 }
 
+#[typegraph(cluster = hunting)]
+pub fn hunt(
+    pack: Vec<raptors::Velociraptor>,
+    #[typegraph(skip)] _turns: u8,
+) -> Option<Ornithomimus> {
+    todo!()
+}
+
+#[typegraph]
+pub async fn feed<T>(_dino: Dinos, _food: Option<T>) {}
+
 #[test]
 fn graphviz() {
     use typegraph::{typegraph, Graphviz};
@@ -245,16 +239,12 @@ fn graphviz() {
         n: u128,
     }
 
-    #[typegraph(generic)]
-    struct _M;
-
     #[typegraph(A)]
     impl Foo {
         fn new() -> Self {
             todo!()
         }
 
-        #[typegraph(generics = [_M])]
         fn make_a_baz<M>(m: M) -> Baz {
             todo!()
         }
@@ -283,3 +273,1215 @@ fn graphviz() {
     let s = Types::render();
     println!("{s}");
 }
+
+#[test]
+fn container_instantiations() {
+    use std::collections::HashMap;
+
+    #[typegraph]
+    struct Holder {
+        a: Vec<u32>,
+        b: Option<isize>,
+        c: Vec<Option<u8>>,
+        d: Option<Vec<u8>>,
+        e: Result<Vec<u8>, Option<u8>>,
+        f: HashMap<u8, Vec<u8>>,
+        g: Result<u8, u8>,
+    }
+
+    let graph = <typegraph::Resolve<Holder> as ValueGraph>::value();
+    // Holder, u32, isize, u8, Vec<u32>, Option<isize>, Option<u8>, Vec<Option<u8>>, Vec<u8>,
+    // Option<Vec<u8>>, Result<Vec<u8>, Option<u8>>, HashMap<u8, Vec<u8>>, Result<u8, u8>
+    assert_eq!(graph.node_count(), 13);
+}
+
+#[test]
+fn trait_implementations() {
+    use typegraph::NodeKind;
+
+    let graph = <typegraph::Resolve<Dinos> as ValueGraph>::value();
+    let carnivore = graph
+        .node_indices()
+        .filter(|ix| matches!(graph[*ix], NodeKind::Trait("trait Carnivore", _, _)))
+        .collect::<Vec<_>>();
+    assert_eq!(carnivore.len(), 1);
+
+    let implementors = graph
+        .raw_edges()
+        .iter()
+        .filter(|e| e.target() == carnivore[0])
+        .map(|e| graph[e.source()].trait_path())
+        .collect::<Vec<_>>();
+    assert_eq!(implementors.len(), 2);
+    assert!(implementors
+        .iter()
+        .all(|t| t.is_some_and(|t| t.ends_with("Carnivore"))));
+}
+
+/// Custom metadata for the trait impls of `Lair` and `Hollow`.
+pub struct LairMeta;
+impl typegraph::Value<typegraph::NodeKind> for LairMeta {
+    fn value() -> typegraph::NodeKind {
+        typegraph::NodeKind::Implementation("custom impl Lair", &[], &[])
+    }
+}
+pub struct HollowMeta;
+impl typegraph::Value<typegraph::NodeKind> for HollowMeta {
+    fn value() -> typegraph::NodeKind {
+        typegraph::NodeKind::Implementation("custom impl Hollow", &[], &[])
+    }
+}
+
+#[typegraph(implementations = [A])]
+pub struct Lair;
+#[typegraph(id = A, meta = LairMeta)]
+impl Carnivore for Lair {
+    type Food = u8;
+    fn eat(&mut self, _food: u8) {}
+}
+
+#[typegraph(implementations = [A])]
+pub struct Hollow;
+#[typegraph(id = A, meta = HollowMeta)]
+impl Carnivore for Hollow {
+    type Food = u8;
+    fn eat(&mut self, _food: u8) {}
+}
+
+#[test]
+fn trait_implementation_meta() {
+    use typegraph::{EdgeKind, NodeKind};
+
+    let graph = <typegraph::Resolve<(Lair, Hollow)> as ValueGraph>::value();
+    // The impls' `meta` describes the impls, not the trait node they share.
+    let carnivore = graph
+        .node_indices()
+        .filter(|ix| matches!(graph[*ix], NodeKind::Trait("trait Carnivore", _, _)))
+        .collect::<Vec<_>>();
+    assert_eq!(carnivore.len(), 1);
+    let implementors = graph
+        .raw_edges()
+        .iter()
+        .filter(|e| matches!(e.weight.kind, EdgeKind::Implements))
+        .map(|e| {
+            assert_eq!(e.target(), carnivore[0]);
+            graph[e.source()].label()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(implementors.len(), 2);
+    assert!(implementors.contains(&"custom impl Lair"));
+    assert!(implementors.contains(&"custom impl Hollow"));
+}
+
+/// Shares its name with `std::fmt::Display`, but not its node.
+#[typegraph]
+pub trait Display {
+    fn show(&self);
+}
+
+#[typegraph(implementations = [A, B, C, D, E])]
+pub struct Plume;
+#[typegraph(id = A)]
+impl From<u8> for Plume {
+    fn from(_eggs: u8) -> Self {
+        Plume
+    }
+}
+#[typegraph(id = B)]
+impl From<u16> for Plume {
+    fn from(_eggs: u16) -> Self {
+        Plume
+    }
+}
+#[typegraph(id = C)]
+impl std::clone::Clone for Plume {
+    fn clone(&self) -> Self {
+        Plume
+    }
+}
+#[typegraph(id = D)]
+impl std::fmt::Display for Plume {
+    #[typegraph(skip)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "plume")
+    }
+}
+#[typegraph(id = E)]
+impl Display for Plume {
+    fn show(&self) {}
+}
+
+#[test]
+fn trait_paths() {
+    use typegraph::{EdgeKind, NodeKind};
+
+    #[typegraph]
+    fn preen<T: Clone>(_feather: T) {}
+
+    type Graph = typegraph::Resolve<(Plume, PreenFn)>;
+    let graph = <Graph as ValueGraph>::value();
+    let traits = |name: &str| {
+        graph
+            .node_indices()
+            .filter(|ix| matches!(graph[*ix], NodeKind::Trait(n, _, _) if n == name))
+            .collect::<Vec<_>>()
+    };
+    let implements = |trait_ix| {
+        graph
+            .raw_edges()
+            .iter()
+            .filter(|e| matches!(e.weight.kind, EdgeKind::Implements) && e.target() == trait_ix)
+            .map(|e| e.weight.label(&e.weight.meta(&graph)))
+            .collect::<Vec<_>>()
+    };
+
+    // `From<u8>` and `From<u16>` share a node, and keep their arguments on their edges.
+    let from = traits("trait From");
+    assert_eq!(from.len(), 1);
+    let mut labels = implements(from[0]);
+    labels.sort();
+    assert_eq!(labels, ["implements (u16)", "implements (u8)"]);
+    // `Clone` in a bound and `std::clone::Clone` in an impl are the same trait.
+    let clone = traits("trait Clone");
+    assert_eq!(clone.len(), 1);
+    assert_eq!(implements(clone[0]).len(), 1);
+    assert!(graph
+        .raw_edges()
+        .iter()
+        .any(|e| matches!(e.weight.kind, EdgeKind::Bound) && e.target() == clone[0]));
+    // The local `Display` isn't `std::fmt::Display`.
+    assert_eq!(traits("trait Display").len(), 2);
+}
+
+#[test]
+fn free_functions() {
+    use typegraph::NodeKind;
+
+    let graph = <typegraph::Resolve<HuntFn> as ValueGraph>::value();
+    let hunt = graph
+        .node_indices()
+        .find(|ix| matches!(graph[*ix], NodeKind::Function("hunt", &["hunting"], _)))
+        .unwrap();
+    let (incoming, outgoing) = graph.raw_edges().iter().fold((0, 0), |(i, o), e| {
+        (
+            i + usize::from(e.target() == hunt),
+            o + usize::from(e.source() == hunt),
+        )
+    });
+    assert_eq!((incoming, outgoing), (1, 1));
+
+    let graph = <typegraph::Resolve<FeedFn> as ValueGraph>::value();
+    assert!(graph
+        .node_weights()
+        .any(|n| matches!(n, NodeKind::AsyncFunction(_, _, args) if args.len() == 2)));
+}
+
+#[test]
+fn call_edges() {
+    use typegraph::NodeKind;
+
+    #[typegraph]
+    fn warm(eggs: u8) -> u8 {
+        eggs
+    }
+
+    #[typegraph(implementations = [A])]
+    struct Nest {
+        eggs: u8,
+    }
+
+    #[typegraph(id = A, trace_calls)]
+    impl Nest {
+        #[typegraph(calls = [warm])]
+        fn lay(&mut self) {
+            self.eggs = warm(self.eggs) + 1;
+            Self::check(self);
+        }
+
+        fn check(&self) -> bool {
+            Nest::count(self) > 0 && self.eggs.is_power_of_two()
+        }
+
+        #[typegraph(calls = [raptors::Velociraptor::into_pack_member, crate::test::hunt])]
+        fn count(&self) -> u8 {
+            self.eggs
+        }
+    }
+
+    #[typegraph(calls = [crate::test::hunt])]
+    fn guard(nest: Nest) {
+        if nest.check() {
+            crate::test::hunt(vec![], 0);
+        }
+    }
+
+    let graph = <typegraph::Resolve<GuardFn> as ValueGraph>::value();
+    let fns = graph
+        .node_indices()
+        .filter_map(|ix| match graph[ix] {
+            NodeKind::Function(name, _, _) => Some((name, ix)),
+            _ => None,
+        })
+        .collect::<std::collections::HashMap<_, _>>();
+    let calls = |from: &str, to: &str| {
+        graph
+            .raw_edges()
+            .iter()
+            .any(|e| e.source() == fns[from] && e.target() == fns[to])
+    };
+
+    assert!(calls("guard", "hunt"));
+    assert!(!calls("guard", "check"));
+    assert!(calls("lay", "warm"));
+    assert!(calls("lay", "check"));
+    assert!(calls("check", "count"));
+    assert!(calls("count", "into_pack_member"));
+    assert!(calls("count", "hunt"));
+    assert!(!calls("lay", "count"));
+}
+
+#[test]
+fn item_paths() {
+    use typegraph::NodeKind;
+
+    mod left {
+        use typegraph::{typegraph, Typegraph};
+
+        #[typegraph(path = test::left)]
+        pub struct Nest {
+            eggs: u8,
+        }
+    }
+
+    mod right {
+        use typegraph::{typegraph, Typegraph};
+
+        #[typegraph(path = test::right)]
+        pub struct Nest {
+            twigs: u16,
+            moss: bool,
+        }
+    }
+
+    let graph = <typegraph::Resolve<(left::Nest, right::Nest)> as ValueGraph>::value();
+    let mut fields = graph
+        .node_weights()
+        .filter_map(|n| match n {
+            NodeKind::Struct("struct Nest", _, fields) => Some(fields.len()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    fields.sort();
+    assert_eq!(fields, [1, 2]);
+}
+
+#[test]
+fn field_accesses() {
+    use typegraph::NodeKind;
+
+    #[typegraph(implementations = [A], trace_fields)]
+    struct Den {
+        occupants: u8,
+        #[typegraph(skip)]
+        log: Vec<String>,
+        capacity: u8,
+    }
+
+    #[typegraph(id = A, trace_fields)]
+    impl Den {
+        fn occupancy(&self) -> u8 {
+            self.occupants
+        }
+
+        fn visits(&self) -> usize {
+            self.log.len()
+        }
+
+        fn enter(&mut self, name: String) {
+            self.occupants += 1;
+            self.log.push(name);
+        }
+
+        fn clear(&mut self) {
+            self.log = vec![];
+        }
+    }
+
+    let graph = <typegraph::Resolve<Den> as ValueGraph>::value();
+    let nodes = graph
+        .node_indices()
+        .filter_map(|ix| match graph[ix] {
+            NodeKind::Function(name, _, _) | NodeKind::Field(name, _) => Some((name, ix)),
+            _ => None,
+        })
+        .collect::<std::collections::HashMap<_, _>>();
+    let edge = |from: &str, to: &str| {
+        graph
+            .raw_edges()
+            .iter()
+            .any(|e| e.source() == nodes[from] && e.target() == nodes[to])
+    };
+
+    assert!(!nodes.contains_key("capacity"));
+    assert!(edge("occupants", "occupancy"));
+    assert!(!edge("occupancy", "occupants"));
+    assert!(edge("occupants", "enter"));
+    assert!(edge("enter", "occupants"));
+    assert!(edge("log", "enter"));
+    assert!(edge("enter", "log"));
+    // A `&self` method can't mutate its fields, whatever it calls on them.
+    assert!(edge("log", "visits"));
+    assert!(!edge("visits", "log"));
+    assert!(edge("clear", "log"));
+    assert!(!edge("log", "clear"));
+}
+
+#[test]
+fn edge_kinds() {
+    use typegraph::{EdgeKind, NodeKind};
+
+    #[typegraph(generics = [_T])]
+    struct Pen<T> {
+        direct: T,
+        wrapped: Option<T>,
+    }
+
+    let graph = <typegraph::Resolve<Pen<u8>> as ValueGraph>::value();
+    let generic = graph
+        .node_indices()
+        .find(|ix| matches!(graph[*ix], NodeKind::Generic("T", _, _)))
+        .unwrap();
+    let kinds = graph
+        .raw_edges()
+        .iter()
+        .filter(|e| e.target() == generic)
+        .map(|e| (graph[e.source()].label(), e.weight.kind))
+        .collect::<Vec<_>>();
+
+    assert_eq!(kinds.len(), 2);
+    assert!(kinds
+        .iter()
+        .any(|(from, kind)| from.starts_with("struct Pen") && matches!(kind, EdgeKind::Property)));
+    assert!(kinds
+        .iter()
+        .any(|(from, kind)| from.starts_with("Option") && matches!(kind, EdgeKind::Contains)));
+    assert!(graph
+        .raw_edges()
+        .iter()
+        .all(|e| !matches!(e.weight.kind, EdgeKind::Unknown)));
+}
+
+#[test]
+fn unions() {
+    use typegraph::{EdgeKind, Graphviz, NodeKind};
+
+    #[typegraph(implementations = [A], trace_fields)]
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    union Register {
+        word: u32,
+        half: u16,
+        #[typegraph(skip)]
+        byte: u8,
+    }
+
+    #[typegraph(id = A, trace_fields)]
+    impl Register {
+        fn low(&self) -> u16 {
+            unsafe { self.half }
+        }
+    }
+
+    type Types = typegraph::Resolve<Register>;
+    let graph = <Types as ValueGraph>::value();
+    let register = graph
+        .node_indices()
+        .find(|ix| matches!(graph[*ix], NodeKind::Union("union Register", _, _)))
+        .unwrap();
+    let NodeKind::Union(_, _, fields) = graph[register] else {
+        unreachable!()
+    };
+    let mut names = fields.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+    names.sort();
+    assert_eq!(names, ["half", "word"]);
+
+    let properties = graph
+        .raw_edges()
+        .iter()
+        .filter(|e| e.source() == register && matches!(e.weight.kind, EdgeKind::Property))
+        .map(|e| graph[e.target()].label())
+        .collect::<Vec<_>>();
+    assert_eq!(properties.len(), 2);
+    assert!(properties.contains(&"u32") && properties.contains(&"u16"));
+    assert!(graph
+        .node_weights()
+        .any(|n| matches!(n, NodeKind::Field("half", _))));
+    assert!(Types::render().contains("shape = \"tab\""));
+}
+
+#[test]
+fn pointers() {
+    use typegraph::NodeKind;
+
+    #[typegraph(implementations = [A<'a>])]
+    struct Aerie<'a> {
+        name: &'a str,
+        eggs: &'a mut [u8],
+        warmth: *const f32,
+        bones: [u16; 32],
+    }
+
+    #[typegraph(id = A)]
+    impl<'a> Aerie<'a> {
+        fn merge(&mut self, other: &Self, extra: &[u8]) -> Option<&u8> {
+            self.eggs.first()
+        }
+
+        fn watch(&self, guard: &dyn Carnivore<Food = u8>, eggs: impl Iterator<Item = u8>) {}
+    }
+
+    let graph = <typegraph::Resolve<Aerie<'static>> as ValueGraph>::value();
+    let containers = graph
+        .node_weights()
+        .filter_map(|n| match n {
+            NodeKind::UnaryContainer(label, _) => Some(*label),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    for label in [
+        "&str",
+        "&mut [u8]",
+        "[u8]",
+        "*const f32",
+        "[u16; _]",
+        "&struct Aerie",
+        "&[u8]",
+        "Option<&u8>",
+        "&u8",
+        "&trait Carnivore",
+    ] {
+        assert!(
+            containers.iter().any(|c| c.starts_with(label)),
+            "missing {label} in {containers:?}"
+        );
+    }
+
+    let NodeKind::Function(_, _, args) = graph
+        .node_weights()
+        .find(|n| n.label().starts_with("merge"))
+        .unwrap()
+    else {
+        unreachable!()
+    };
+    assert_eq!(args.len(), 2);
+
+    let NodeKind::Function(_, _, args) = graph
+        .node_weights()
+        .find(|n| n.label().starts_with("watch"))
+        .unwrap()
+    else {
+        unreachable!()
+    };
+    assert_eq!(args.len(), 2);
+    assert!(graph
+        .node_weights()
+        .any(|n| matches!(n, NodeKind::Trait("trait Iterator", _, _))));
+}
+
+#[test]
+fn tuples() {
+    use typegraph::{EdgeKind, Graphviz, NodeKind};
+
+    #[typegraph]
+    struct Clutch {
+        eggs: (u8, u16, u8, Option<u8>),
+        single: (bool,),
+    }
+
+    type Types = typegraph::Resolve<Clutch>;
+    let graph = <Types as ValueGraph>::value();
+    let (tuple, fields) = graph
+        .node_indices()
+        .find_map(|ix| match graph[ix] {
+            NodeKind::Tuple("(u8, u16, u8, Option<u8>)", _, fields) => Some((ix, fields)),
+            _ => None,
+        })
+        .unwrap();
+    let mut positions = fields.iter().map(|(p, _)| *p).collect::<Vec<_>>();
+    positions.sort();
+    assert_eq!(positions, [".0, .2", ".1", ".3"]);
+    assert_eq!(
+        graph
+            .raw_edges()
+            .iter()
+            .filter(|e| e.source() == tuple && matches!(e.weight.kind, EdgeKind::Property))
+            .count(),
+        3
+    );
+    assert!(graph
+        .node_weights()
+        .any(|n| matches!(n, NodeKind::Tuple("(bool,)", _, _))));
+    assert!(Types::render().contains("field (.1)"));
+}
+
+#[test]
+fn std_catalogue() {
+    use std::borrow::Cow;
+    use std::cell::Cell;
+    use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashSet, LinkedList};
+    use std::num::NonZeroU32;
+    use std::ops::Range;
+    use std::path::PathBuf;
+    use std::rc::Rc;
+    use std::sync::atomic::AtomicU64;
+    use std::sync::mpsc::{Receiver, Sender};
+    use std::sync::{OnceLock, RwLock};
+    use std::time::{Instant, SystemTime};
+
+    use typegraph::NodeKind;
+
+    #[typegraph]
+    struct Museum {
+        exhibits: BTreeMap<u32, BTreeSet<u8>>,
+        visitors: HashSet<u64>,
+        queue: BinaryHeap<u16>,
+        log: LinkedList<Cow<'static, str>>,
+        shared: Rc<Cell<u8>>,
+        lock: RwLock<OnceLock<PathBuf>>,
+        hours: Range<Instant>,
+        opened: SystemTime,
+        count: AtomicU64,
+        id: NonZeroU32,
+        tx: Sender<u8>,
+        rx: Receiver<u8>,
+    }
+
+    let graph = <typegraph::Resolve<Museum> as ValueGraph>::value();
+    let labels = graph
+        .node_weights()
+        .map(NodeKind::label)
+        .collect::<Vec<_>>();
+    for label in [
+        "BTreeMap<u32, BTreeSet<u8>>",
+        "HashSet<u64>",
+        "BinaryHeap<u16>",
+        "LinkedList<Cow<str>>",
+        "Rc<Cell<u8>>",
+        "RwLock<OnceLock<PathBuf>>",
+        "Range<Instant>",
+        "SystemTime",
+        "AtomicU64",
+        "NonZeroU32",
+        "Sender<u8>",
+        "Receiver<u8>",
+    ] {
+        assert!(labels.contains(&label), "missing {label} in {labels:?}");
+    }
+}
+
+#[test]
+fn ecosystem() {
+    use chrono::{DateTime, Utc};
+    use indexmap::IndexMap;
+    use typegraph::NodeKind;
+    use uuid::Uuid;
+
+    #[typegraph]
+    struct Sighting {
+        id: Uuid,
+        at: DateTime<Utc>,
+        witnesses: IndexMap<Uuid, String>,
+    }
+
+    let graph = <typegraph::Resolve<Sighting> as ValueGraph>::value();
+    let labels = graph
+        .node_weights()
+        .map(NodeKind::label)
+        .collect::<Vec<_>>();
+    for label in [
+        "uuid::Uuid",
+        "DateTime<chrono::Utc>",
+        "IndexMap<uuid::Uuid, String>",
+    ] {
+        assert!(labels.contains(&label), "missing {label} in {labels:?}");
+    }
+}
+
+#[test]
+fn remote() {
+    use typegraph::NodeKind;
+
+    mod quarry {
+        pub struct Fossil {
+            pub age: u32,
+        }
+    }
+
+    #[typegraph(remote = quarry::Fossil)]
+    struct FossilDef {
+        age: u32,
+    }
+
+    #[typegraph]
+    struct Exhibit {
+        #[typegraph(force = FossilDef)]
+        centerpiece: quarry::Fossil,
+        #[typegraph(force = Vec<FossilDef>)]
+        archive: Vec<quarry::Fossil>,
+    }
+
+    let graph = <typegraph::Resolve<Exhibit> as ValueGraph>::value();
+    let labels = graph
+        .node_weights()
+        .map(NodeKind::label)
+        .collect::<Vec<_>>();
+    for label in ["struct quarry::Fossil", "Vec<struct quarry::Fossil>", "u32"] {
+        assert!(labels.contains(&label), "missing {label} in {labels:?}");
+    }
+    assert!(!labels.iter().any(|l| l.contains("FossilDef")));
+}
+
+#[test]
+fn automatic_generics() {
+    use typegraph::NodeKind;
+
+    #[typegraph]
+    struct Hatchery<T: Clone, U>
+    where
+        U: Carnivore + Default,
+    {
+        eggs: Vec<T>,
+        guard: Option<U>,
+    }
+
+    #[typegraph]
+    fn hatch<T: Clone>(eggs: Vec<T>) -> Option<T> {
+        todo!()
+    }
+
+    let graph = <typegraph::Resolve<(HatchFn, Hatchery<u8, Guard>)> as ValueGraph>::value();
+    let generics = graph
+        .node_weights()
+        .filter(|n| matches!(n, NodeKind::Generic(..)))
+        .map(NodeKind::label)
+        .collect::<Vec<_>>();
+    // Each item gets its own placeholders, so the two `T`s stay apart.
+    assert_eq!(generics.len(), 3, "{generics:?}");
+    assert_eq!(generics.iter().filter(|l| **l == "T: Clone").count(), 2);
+    assert!(generics.contains(&"U: Carnivore + Default"));
+}
+
+#[derive(Default)]
+#[typegraph]
+pub struct Guard;
+impl Carnivore for Guard {
+    type Food = u8;
+    fn eat(&mut self, _food: u8) {}
+}
+
+#[test]
+fn bound_edges() {
+    use typegraph::{EdgeKind, Graphviz, NodeKind};
+
+    #[typegraph]
+    fn stalk<'a, P>(prey: &'a P) -> Option<P>
+    where
+        P: Carnivore<Food = u8> + Clone + 'a,
+    {
+        todo!()
+    }
+
+    let graph = <typegraph::Resolve<(StalkFn, Dinos)> as ValueGraph>::value();
+    let bounds = graph
+        .raw_edges()
+        .iter()
+        .filter(|e| matches!(e.weight.kind, EdgeKind::Bound))
+        .map(|e| (graph[e.source()].label(), graph[e.target()].label()))
+        .collect::<Vec<_>>();
+    assert_eq!(bounds.len(), 2, "{bounds:?}");
+    assert!(bounds
+        .iter()
+        .all(|(from, _)| from.starts_with("P: Carnivore")));
+    assert!(bounds.iter().any(|(_, to)| *to == "trait Carnivore"));
+    assert!(bounds.iter().any(|(_, to)| *to == "trait Clone"));
+    // The bound shares the trait node of the impls.
+    let carnivores = graph
+        .node_weights()
+        .filter(|n| matches!(n, NodeKind::Trait("trait Carnivore", _, _)))
+        .count();
+    assert_eq!(carnivores, 1);
+
+    let dot = <typegraph::Resolve<StalkFn> as Graphviz>::render();
+    assert!(dot.contains("label = \"bound (Food = u8)\""), "{dot}");
+}
+
+#[test]
+fn associated_items() {
+    use typegraph::{EdgeKind, Graphviz};
+
+    trait Clutch {
+        const EGGS: u8;
+        type Egg;
+        type Guardian;
+    }
+
+    #[typegraph(implementations = [A])]
+    struct Burrow;
+
+    #[typegraph(id = A)]
+    impl Clutch for Burrow {
+        const EGGS: u8 = 4;
+        type Egg = u8;
+        type Guardian = raptors::Velociraptor;
+    }
+
+    let graph = <typegraph::Resolve<(Burrow, raptors::Velociraptor)> as ValueGraph>::value();
+    let associated = graph
+        .raw_edges()
+        .iter()
+        .filter(|e| matches!(e.weight.kind, EdgeKind::Associated))
+        .map(|e| (graph[e.source()].label(), graph[e.target()].label()))
+        .collect::<Vec<_>>();
+    assert_eq!(associated.len(), 3, "{associated:?}");
+    assert!(associated.contains(&("impl Clutch for Burrow", "u8")));
+    assert!(associated.contains(&("impl Clutch for Burrow", "struct Velociraptor")));
+    // `Food = Ornithomimus` shows up although `eat` also takes it as an argument.
+    assert!(associated
+        .iter()
+        .any(|(from, to)| from.ends_with("Carnivore for Velociraptor")
+            && *to == "struct Ornithomimus"));
+
+    let dot = <typegraph::Resolve<Burrow> as Graphviz>::render();
+    assert!(dot.contains("label = \"const EGGS, type Egg\""), "{dot}");
+    assert!(dot.contains("label = \"type Guardian\""), "{dot}");
+}
+
+#[test]
+fn trait_definitions() {
+    use typegraph::{EdgeKind, Graphviz, NodeKind};
+
+    #[typegraph]
+    pub trait Predator: Carnivore<Food = u8> + Clone {
+        const PACK: u8;
+        type Prey: Clone;
+        fn pounce(&self, prey: Self::Prey) -> Option<Self>;
+        fn rest(&self) {}
+    }
+
+    type Graph = typegraph::Resolve<(PredatorTrait, CarnivoreTrait, raptors::Velociraptor)>;
+    let graph = <Graph as ValueGraph>::value();
+    let edges = graph
+        .raw_edges()
+        .iter()
+        .map(|e| {
+            (
+                graph[e.source()].label(),
+                e.weight.kind,
+                graph[e.target()].label(),
+            )
+        })
+        .collect::<Vec<_>>();
+    let from_predator = |kind: fn(&EdgeKind) -> bool, to: &str| {
+        edges
+            .iter()
+            .any(|(f, k, t)| *f == "trait Predator" && kind(k) && t.starts_with(to))
+    };
+    assert!(from_predator(
+        |k| matches!(k, EdgeKind::Bound),
+        "trait Carnivore"
+    ));
+    assert!(from_predator(
+        |k| matches!(k, EdgeKind::Bound),
+        "trait Clone"
+    ));
+    assert!(from_predator(|k| matches!(k, EdgeKind::Associated), "u8"));
+    assert!(from_predator(|k| matches!(k, EdgeKind::Associated), "Prey"));
+    assert!(from_predator(|k| matches!(k, EdgeKind::Function), "pounce"));
+    assert!(from_predator(|k| matches!(k, EdgeKind::Function), "rest"));
+    // `Self` is the trait itself.
+    assert!(edges.iter().any(|(f, k, t)| f.starts_with("pounce")
+        && matches!(k, EdgeKind::Returns)
+        && *t == "Option<trait Predator>"));
+
+    // The definition and the impls share the trait node, so the impl links back to `eat`.
+    let carnivores = graph
+        .node_indices()
+        .filter(|ix| matches!(graph[*ix], NodeKind::Trait("trait Carnivore", _, _)))
+        .collect::<Vec<_>>();
+    assert_eq!(carnivores.len(), 1);
+    assert!(edges
+        .iter()
+        .any(|(f, k, t)| f.ends_with("Carnivore for Velociraptor")
+            && matches!(k, EdgeKind::Implements)
+            && *t == "trait Carnivore"));
+    assert!(edges.iter().any(|(f, k, t)| *f == "trait Carnivore"
+        && matches!(k, EdgeKind::Function)
+        && *t == "eat"));
+
+    let dot = <typegraph::Resolve<PredatorTrait> as Graphviz>::render();
+    for label in [
+        "fn (required)",
+        "fn (provided)",
+        "const PACK",
+        "type Prey",
+        "bound (Food = u8)",
+    ] {
+        assert!(
+            dot.contains(&format!("label = \"{label}\"")),
+            "missing {label} in {dot}"
+        );
+    }
+}
+
+/// Custom metadata for `Stalker`.
+pub struct StalkerMeta;
+impl typegraph::Value<typegraph::NodeKind> for StalkerMeta {
+    fn value() -> typegraph::NodeKind {
+        typegraph::NodeKind::Trait("custom trait Stalker", &[], &[])
+    }
+}
+
+#[test]
+fn trait_meta() {
+    use typegraph::NodeKind;
+
+    #[typegraph(meta = crate::test::StalkerMeta)]
+    pub trait Stalker: Carnivore {
+        fn stalk(&self);
+    }
+
+    let graph = <typegraph::Resolve<StalkerTrait> as ValueGraph>::value();
+    let traits = graph
+        .node_weights()
+        .filter(|n| matches!(n, NodeKind::Trait(..)))
+        .map(NodeKind::label)
+        .collect::<Vec<_>>();
+    // The supertrait's node is shared, so it isn't described by the subtrait's `meta`.
+    assert!(traits.contains(&"custom trait Stalker"));
+    assert!(traits.contains(&"trait Carnivore"));
+}
+
+#[test]
+fn associated_type_names() {
+    use typegraph::{EdgeKind, NodeKind};
+
+    mod parsing {
+        use super::*;
+
+        #[typegraph]
+        pub struct Error {
+            line: u32,
+        }
+    }
+
+    #[typegraph]
+    pub trait Parser {
+        type Error;
+        fn parse(&self, input: u8) -> Option<parsing::Error>;
+        fn recover(&self, error: <Self as Parser>::Error) -> Self::Error;
+    }
+
+    let graph = <typegraph::Resolve<ParserTrait> as ValueGraph>::value();
+    let edges = graph
+        .raw_edges()
+        .iter()
+        .map(|e| {
+            (
+                graph[e.source()].label(),
+                e.weight.kind,
+                graph[e.target()].label(),
+            )
+        })
+        .collect::<Vec<_>>();
+    // A type named like an associated type is still itself.
+    assert!(edges.iter().any(|(f, k, t)| f.starts_with("parse")
+        && matches!(k, EdgeKind::Returns)
+        && *t == "Option<struct Error>"));
+    // Both ways of naming the associated type lead to its placeholder.
+    let placeholder = |label: &str| {
+        graph
+            .node_indices()
+            .any(|ix| graph[ix].label() == label && matches!(graph[ix], NodeKind::Generic(..)))
+    };
+    assert!(placeholder("Error"));
+    assert!(edges.iter().any(|(f, k, t)| *f == "Error"
+        && matches!(k, EdgeKind::Argument)
+        && t.starts_with("recover")));
+    assert!(edges.iter().any(|(f, k, t)| f.starts_with("recover")
+        && matches!(k, EdgeKind::Returns)
+        && *t == "Error"));
+}
+
+#[test]
+fn type_aliases() {
+    use typegraph::{EdgeKind, NodeKind};
+
+    #[typegraph]
+    pub struct AppError {
+        code: u16,
+    }
+
+    #[typegraph(cluster = errors)]
+    pub type AppResult<T> = Result<T, AppError>;
+
+    #[typegraph(transparent)]
+    pub type Hunters = Vec<raptors::Velociraptor>;
+
+    let graph = <typegraph::Resolve<AppResultAlias> as ValueGraph>::value();
+    let alias = graph
+        .node_indices()
+        .find(|ix| matches!(graph[*ix], NodeKind::Alias(..)))
+        .unwrap();
+    assert!(graph[alias].label().starts_with("type AppResult"));
+    assert_eq!(graph[alias].cluster(), &["errors"]);
+    let targets = graph
+        .raw_edges()
+        .iter()
+        .filter(|e| e.source() == alias && matches!(e.weight.kind, EdgeKind::Alias))
+        .map(|e| graph[e.target()].label())
+        .collect::<Vec<_>>();
+    assert_eq!(targets, ["Result<T, struct AppError>"]);
+
+    // A transparent alias has no node of its own.
+    let graph = <typegraph::Resolve<HuntersAlias> as ValueGraph>::value();
+    let labels = graph
+        .node_weights()
+        .map(NodeKind::label)
+        .collect::<Vec<_>>();
+    assert!(labels.contains(&"Vec<struct Velociraptor>"), "{labels:?}");
+    assert!(!graph
+        .node_weights()
+        .any(|n| matches!(n, NodeKind::Alias(..))));
+}
+
+#[test]
+fn mermaid() {
+    use typegraph::Mermaid;
+
+    #[typegraph(implementations = [A, B], cluster = aviary)]
+    pub struct Roost {
+        perches: u8,
+        guard: Guard,
+    }
+
+    #[typegraph(id = A)]
+    impl Roost {
+        fn occupy(&mut self, perch: u8) -> bool {
+            todo!()
+        }
+    }
+
+    #[typegraph(id = B)]
+    impl Carnivore for Roost {
+        type Food = u16;
+        fn eat(&mut self, food: u16) {}
+    }
+
+    let diagram = <typegraph::Resolve<Roost> as Mermaid>::render();
+    let class = |label: &str| {
+        diagram
+            .lines()
+            .find_map(|l| {
+                l.trim()
+                    .trim_end_matches(" {")
+                    .strip_prefix("class ")?
+                    .strip_suffix(&format!("[\"{label}\"]"))
+            })
+            .unwrap_or_else(|| panic!("no class for {label} in {diagram}"))
+    };
+    let (roost, guard, carnivore) = (
+        class("struct Roost"),
+        class("struct Guard"),
+        class("trait Carnivore"),
+    );
+
+    assert!(diagram.starts_with("classDiagram"));
+    assert!(diagram.contains("namespace aviary {"));
+    for member in [
+        "perches: u8",
+        "guard: struct Guard",
+        "occupy(perch: u8) bool",
+        "eat(food: u16)",
+    ] {
+        assert!(
+            diagram.lines().any(|l| l.trim() == member),
+            "missing {member} in {diagram}"
+        );
+    }
+    assert!(diagram.contains(&format!("{roost} *-- {guard} : guard")));
+    assert!(diagram.contains(&format!("{carnivore} <|.. {roost}")));
+    // Impls are folded into their self type.
+    assert!(!diagram.contains("impl"));
+}
+
+#[test]
+fn plantuml() {
+    use typegraph::PlantUml;
+
+    #[typegraph(implementations = [A], cluster = aviary::lofts)]
+    pub struct Loft {
+        perches: Vec<u8>,
+    }
+
+    #[typegraph(id = A)]
+    impl Loft {
+        fn settle(&mut self, depth: u32) -> Option<u8> {
+            todo!()
+        }
+    }
+
+    #[typegraph(cluster = aviary)]
+    pub fn perch<P>(loft: Loft, bird: P) {}
+
+    let diagram = <typegraph::Resolve<PerchFn> as PlantUml>::render();
+    let lines = diagram.lines().map(str::trim).collect::<Vec<_>>();
+    assert_eq!(lines.first(), Some(&"@startuml"));
+    assert_eq!(lines.last(), Some(&"@enduml"));
+    assert!(diagram.contains("package aviary {\n    class"));
+    assert!(diagram.contains("    package lofts {\n        class \"struct Loft\""));
+    for (label, stereotype) in [
+        ("P", "generic"),
+        ("Vec<u8>", "container"),
+        ("u8", "primitive"),
+    ] {
+        assert!(
+            lines
+                .iter()
+                .any(|l| l.starts_with(&format!("class \"{label}\""))
+                    && l.ends_with(&format!("<<{stereotype}>>"))),
+            "missing <<{stereotype}>> {label} in {diagram}"
+        );
+    }
+    assert!(lines.contains(&"settle(depth: u32): Option<u8>"));
+    assert!(lines
+        .iter()
+        .any(|l| l.ends_with("(loft: struct Loft, bird: P)")));
+}
+
+#[test]
+fn json() {
+    use typegraph::json::{Json, SCHEMA_VERSION};
+
+    #[typegraph(cluster = aviary)]
+    pub struct Feeder {
+        seeds: u16,
+    }
+
+    #[typegraph]
+    pub fn refill(feeder: Feeder, amount: u16) -> bool {
+        todo!()
+    }
+
+    let document =
+        serde_json::to_value(<typegraph::Resolve<RefillFn> as Json>::document()).unwrap();
+    assert_eq!(document["schema_version"], SCHEMA_VERSION);
+    let nodes = document["nodes"].as_array().unwrap();
+    let node = |label: &str| {
+        nodes
+            .iter()
+            .find(|n| n["label"] == label)
+            .unwrap_or_else(|| panic!("missing {label} in {nodes:?}"))
+    };
+    let (feeder, refill, seeds) = (node("struct Feeder"), node("refill"), node("u16"));
+    assert_eq!(feeder["kind"], "struct");
+    assert_eq!(feeder["clusters"], serde_json::json!(["aviary"]));
+    assert_eq!(
+        feeder["fields"],
+        serde_json::json!([{ "name": "seeds", "node": seeds["id"] }])
+    );
+    assert_eq!(refill["kind"], "function");
+    assert_eq!(
+        refill["arguments"],
+        serde_json::json!([
+            { "name": "feeder", "node": feeder["id"] },
+            { "name": "amount", "node": seeds["id"] },
+        ])
+    );
+
+    let edges = document["edges"].as_array().unwrap();
+    assert!(edges.contains(&serde_json::json!({
+        "from": feeder["id"],
+        "to": seeds["id"],
+        "kind": "property",
+        "label": "field (seeds)",
+    })));
+    assert!(edges
+        .iter()
+        .any(|e| e["from"] == refill["id"] && e["kind"] == "returns"));
+}
+
+#[test]
+fn graphml() {
+    use typegraph::GraphMl;
+
+    #[typegraph(cluster = aviary::lofts)]
+    pub struct Trough {
+        grains: Vec<u8>,
+    }
+
+    let graphml = <typegraph::Resolve<Trough> as GraphMl>::render();
+    assert!(graphml.starts_with("<?xml"));
+    assert!(graphml.contains(r#"<graph id="typegraph" edgedefault="directed">"#));
+    assert!(graphml.contains(
+        r#"<data key="label">struct Trough</data><data key="kind">struct</data><data key="cluster">aviary::lofts</data>"#
+    ));
+    assert!(graphml.contains(r#"<data key="label">Vec&lt;u8&gt;</data>"#));
+    assert!(graphml.contains(
+        r#"<data key="edge_label">field (grains)</data><data key="edge_kind">property</data>"#
+    ));
+    assert_eq!(graphml.matches("<node ").count(), 3);
+    assert_eq!(graphml.matches("<edge ").count(), 2);
+}
+
+#[test]
+fn gexf() {
+    use typegraph::Gexf;
+
+    #[typegraph(cluster = aviary)]
+    pub struct Perch {
+        height: Option<u8>,
+    }
+
+    let gexf = <typegraph::Resolve<Perch> as Gexf>::render();
+    assert!(gexf.contains(r#"<gexf xmlns="http://gexf.net/1.3" version="1.3">"#));
+    assert!(gexf.contains(
+        r#"label="struct Perch"><attvalues><attvalue for="kind" value="struct"/><attvalue for="cluster" value="aviary"/>"#
+    ));
+    assert!(gexf.contains(r#"label="Option&lt;u8&gt;""#));
+    assert!(gexf
+        .contains(r#"label="field (height)"><attvalues><attvalue for="kind" value="property"/>"#));
+    assert_eq!(gexf.matches("<node ").count(), 3);
+    assert_eq!(gexf.matches("<edge ").count(), 2);
+}
+
+#[test]
+fn cypher() {
+    use typegraph::Cypher;
+
+    #[typegraph(cluster = aviary)]
+    pub struct Song<'a> {
+        notes: &'a str,
+    }
+
+    fn id<T>() -> u32
+    where
+        T: Typegraph + ?Sized,
+        <T as Typegraph>::Id: typegraph::Unsigned,
+    {
+        <<T as Typegraph>::Id as typegraph::Unsigned>::U32
+    }
+    let (song, notes, str) = (id::<Song<'static>>(), id::<&str>(), id::<str>());
+
+    let script = <typegraph::Resolve<Song<'static>> as Cypher>::render();
+    assert_eq!(
+        script,
+        [
+            format!("MERGE (n:Typegraph {{id: {str}}}) SET n:Primitive, n.id = {str}, n.kind = 'primitive', n.label = 'str', n.clusters = [];"),
+            format!("MERGE (n:Typegraph {{id: {notes}}}) SET n:UnaryContainer, n.id = {notes}, n.kind = 'unary_container', n.label = '&str', n.clusters = [];"),
+            format!("MERGE (n:Typegraph {{path: 'type typegraph_test::test::Song'}}) SET n:Struct, n.id = {song}, n.kind = 'struct', n.label = 'struct Song < \\'a >', n.clusters = ['aviary'];"),
+            format!("MATCH (a:Typegraph {{id: {notes}}}), (b:Typegraph {{id: {str}}}) MERGE (a)-[r:CONTAINS]->(b) SET r.label = 'content';"),
+            format!("MATCH (a:Typegraph {{path: 'type typegraph_test::test::Song'}}), (b:Typegraph {{id: {notes}}}) MERGE (a)-[r:PROPERTY]->(b) SET r.label = 'field (notes)';"),
+        ]
+        .join("\n")
+    );
+}
@@ -1,26 +1,56 @@
-use core::ops::Add;
-use std::boxed::Box;
-use std::cell::RefCell;
-use std::collections::{HashMap, VecDeque};
+//! Generic types from `core`, `alloc` and `std`. Unary containers are allocated IDs from 100,
+//! binary containers from 200.
+
+use core::cell::{Cell, OnceCell, RefCell, UnsafeCell};
+use core::cmp::Reverse;
+use core::marker::PhantomData;
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::num::{Saturating, Wrapping};
+use core::ops::{Bound, Range, RangeFrom, RangeInclusive, RangeTo, RangeToInclusive};
+use core::pin::Pin;
+use core::ptr::NonNull;
+use core::task::Poll;
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque};
+#[cfg(feature = "alloc")]
+use alloc::rc::{Rc, Weak as RcWeak};
+#[cfg(feature = "alloc")]
+use alloc::sync::{Arc, Weak};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+#[cfg(feature = "value")]
 use std::format;
-use std::marker::PhantomData;
-use std::sync::{Arc, Mutex, Weak};
+#[cfg(feature = "std")]
+use std::sync::mpsc::{Receiver, Sender, SyncSender};
+#[cfg(feature = "std")]
+use std::sync::{LazyLock, Mutex, OnceLock, RwLock};
+#[cfg(feature = "std")]
 use std::thread::JoinHandle;
-use std::vec::Vec;
 
-use typosaurus::bool::{False, Falsy, Or};
 use typosaurus::cmp::Equality;
 use typosaurus::num::consts::*;
 use typosaurus::{list, set};
 
-use crate::{Edge, NodeOutput, NodeOutputData, NodeOutputKind, Typegraph};
+use crate::{
+    edge, InstanceId, NoParams, NodeOutput, NodeOutputData, NodeOutputKind, ParamIds, Select,
+    TypedEdge, Typegraph,
+};
 #[cfg(feature = "value")]
 use crate::{NodeKind, Value};
 
 #[cfg(feature = "value")]
 macro_rules! impl_unary_container {
     ($t:ident => $node:ident,$id:ty,$vid:literal,$label:literal) => {
-        pub struct $node<T>(std::marker::PhantomData<T>);
+        impl_unary_container!([T] $t<T> => $node, $id, $vid, $label);
+    };
+    ([$($generics:tt)*] $t:ty => $node:ident,$id:ty,$vid:literal,$label:literal) => {
+        pub struct $node<T>(PhantomData<T>);
         impl<T> $crate::Value<$crate::NodeKind> for $node<T>
         where
             T: $crate::Value<$crate::NodeKind>,
@@ -36,24 +66,22 @@ macro_rules! impl_unary_container {
         where
             T: $crate::NodeOutputData,
         {
-            const ID: u32 = <T as $crate::NodeOutputData>::ID + $vid;
+            const ID: u32 = $crate::instance_id($vid, &[<T as $crate::NodeOutputData>::ID]);
             const KIND: $crate::NodeOutputKind = $crate::NodeOutputKind::UnaryContainer;
             const NAME: &'static str = stringify!($n<T>);
         }
-        impl<T> $crate::Typegraph for $t<T>
+        impl<$($generics)*> $crate::Typegraph for $t
         where
             T: $crate::Typegraph,
-            <T as $crate::Typegraph>::Id: core::ops::Add<$id>,
         {
-            type Id = <<T as $crate::Typegraph>::Id as core::ops::Add<$id>>::Output;
+            type Id = $crate::InstanceId<$id, $crate::ParamIds<<T as $crate::Typegraph>::Id, $crate::NoParams>>;
             type Node = $node<<T as $crate::Typegraph>::Node>;
             type Nodes = $crate::set![$crate::NodeOutput<
-                                                        <<T as $crate::Typegraph>::Id as core::ops::Add<$id>>::Output,
+                                                        Self::Id,
                                                         $node<<T as $crate::Typegraph>::Node>,
                                                         $node<<T as $crate::Typegraph>::Node>,
                                                     >];
-            type Edges =
-                $crate::set![$crate::Edge<<<T as $crate::Typegraph>::Id as core::ops::Add<$id>>::Output, <T as $crate::Typegraph>::Id>];
+            type Edges = $crate::set![$crate::TypedEdge<Self::Id, <T as $crate::Typegraph>::Id, $crate::edge::Contains>];
             type Types = $crate::list![T];
         }
     };
@@ -61,48 +89,102 @@ macro_rules! impl_unary_container {
 #[cfg(not(feature = "value"))]
 macro_rules! impl_unary_container {
     ($t:ident => $node:ident,$id:ty,$vid:literal,$label:literal) => {
-        pub struct $node<T>(std::marker::PhantomData<T>);
+        impl_unary_container!([T] $t<T> => $node, $id, $vid, $label);
+    };
+    ([$($generics:tt)*] $t:ty => $node:ident,$id:ty,$vid:literal,$label:literal) => {
+        pub struct $node<T>(PhantomData<T>);
         impl<T> $crate::NodeOutputData for $node<T>
         where
             T: $crate::NodeOutputData,
         {
-            const ID: u32 = <T as $crate::NodeOutputData>::ID + $vid;
+            const ID: u32 = $crate::instance_id($vid, &[<T as $crate::NodeOutputData>::ID]);
             const KIND: $crate::NodeOutputKind = $crate::NodeOutputKind::UnaryContainer;
             const NAME: &'static str = stringify!($n<T>);
         }
-        impl<T> $crate::Typegraph for $t<T>
+        impl<$($generics)*> $crate::Typegraph for $t
         where
             T: $crate::Typegraph,
-            <T as $crate::Typegraph>::Id: core::ops::Add<$id>,
         {
-            type Id = <<T as $crate::Typegraph>::Id as core::ops::Add<$id>>::Output;
+            type Id = $crate::InstanceId<$id, $crate::ParamIds<<T as $crate::Typegraph>::Id, $crate::NoParams>>;
             type Node = $node<<T as $crate::Typegraph>::Node>;
             type Nodes = $crate::set![$crate::NodeOutput<
-                                                        <<T as $crate::Typegraph>::Id as core::ops::Add<$id>>::Output,
+                                                        Self::Id,
                                                         $node<<T as $crate::Typegraph>::Node>,
                                                         $node<<T as $crate::Typegraph>::Node>,
                                                     >];
-            type Edges =
-                $crate::set![$crate::Edge<<<T as $crate::Typegraph>::Id as core::ops::Add<$id>>::Output, <T as $crate::Typegraph>::Id>];
+            type Edges = $crate::set![$crate::TypedEdge<Self::Id, <T as $crate::Typegraph>::Id, $crate::edge::Contains>];
             type Types = $crate::list![T];
         }
     };
 }
 
+#[cfg(feature = "alloc")]
 impl_unary_container!(Vec => VecNode, U100, 100, "Vec<{}>");
+#[cfg(feature = "std")]
 impl_unary_container!(Mutex => MutexNode, U101, 101, "Mutex<{}>");
+#[cfg(feature = "alloc")]
 impl_unary_container!(Arc => ArcNode, U102, 102, "Arc<{}>");
 impl_unary_container!(Option => OptionNode, U103, 103, "Option<{}>");
+#[cfg(feature = "std")]
 impl_unary_container!(JoinHandle => JoinHandleNode, U104, 104, "JoinHandle<{}>");
+#[cfg(feature = "alloc")]
 impl_unary_container!(VecDeque => VecDequeNode, U105, 105, "VecDeque<{}>");
+#[cfg(feature = "alloc")]
 impl_unary_container!(Weak => WeakNode, U106, 106, "Weak<{}>");
 impl_unary_container!(RefCell => RefCellNode, U107, 107, "RefCell<{}>");
+#[cfg(feature = "alloc")]
 impl_unary_container!(Box => BoxNode, U108, 108, "Box<{}>");
 impl_unary_container!(PhantomData => PhantomDataNode, U109, 109, "PhantomData<{}>");
+// 110 through 115 are references, pointers, slices and arrays.
+#[cfg(feature = "alloc")]
+impl_unary_container!(BTreeSet => BTreeSetNode, U116, 116, "BTreeSet<{}>");
+#[cfg(feature = "std")]
+impl_unary_container!(HashSet => HashSetNode, U117, 117, "HashSet<{}>");
+#[cfg(feature = "alloc")]
+impl_unary_container!(BinaryHeap => BinaryHeapNode, U118, 118, "BinaryHeap<{}>");
+#[cfg(feature = "alloc")]
+impl_unary_container!(LinkedList => LinkedListNode, U119, 119, "LinkedList<{}>");
+#[cfg(feature = "alloc")]
+impl_unary_container!(Rc => RcNode, U120, 120, "Rc<{}>");
+#[cfg(feature = "alloc")]
+impl_unary_container!(RcWeak => RcWeakNode, U121, 121, "rc::Weak<{}>");
+impl_unary_container!(Cell => CellNode, U122, 122, "Cell<{}>");
+impl_unary_container!(UnsafeCell => UnsafeCellNode, U123, 123, "UnsafeCell<{}>");
+impl_unary_container!(OnceCell => OnceCellNode, U124, 124, "OnceCell<{}>");
+#[cfg(feature = "std")]
+impl_unary_container!(RwLock => RwLockNode, U125, 125, "RwLock<{}>");
+#[cfg(feature = "std")]
+impl_unary_container!(OnceLock => OnceLockNode, U126, 126, "OnceLock<{}>");
+#[cfg(feature = "std")]
+impl_unary_container!(LazyLock => LazyLockNode, U127, 127, "LazyLock<{}>");
+impl_unary_container!(Pin => PinNode, U128, 128, "Pin<{}>");
+impl_unary_container!(NonNull => NonNullNode, U129, 129, "NonNull<{}>");
+impl_unary_container!(ManuallyDrop => ManuallyDropNode, U130, 130, "ManuallyDrop<{}>");
+impl_unary_container!(MaybeUninit => MaybeUninitNode, U131, 131, "MaybeUninit<{}>");
+impl_unary_container!(Wrapping => WrappingNode, U132, 132, "Wrapping<{}>");
+impl_unary_container!(Saturating => SaturatingNode, U133, 133, "Saturating<{}>");
+impl_unary_container!(Reverse => ReverseNode, U134, 134, "Reverse<{}>");
+impl_unary_container!(Range => RangeNode, U135, 135, "Range<{}>");
+impl_unary_container!(RangeInclusive => RangeInclusiveNode, U136, 136, "RangeInclusive<{}>");
+impl_unary_container!(RangeFrom => RangeFromNode, U137, 137, "RangeFrom<{}>");
+impl_unary_container!(RangeTo => RangeToNode, U138, 138, "RangeTo<{}>");
+impl_unary_container!(RangeToInclusive => RangeToInclusiveNode, U139, 139, "RangeToInclusive<{}>");
+impl_unary_container!(Bound => BoundNode, U140, 140, "Bound<{}>");
+impl_unary_container!(Poll => PollNode, U141, 141, "Poll<{}>");
+#[cfg(feature = "std")]
+impl_unary_container!(Sender => SenderNode, U142, 142, "Sender<{}>");
+#[cfg(feature = "std")]
+impl_unary_container!(SyncSender => SyncSenderNode, U143, 143, "SyncSender<{}>");
+#[cfg(feature = "std")]
+impl_unary_container!(Receiver => ReceiverNode, U144, 144, "Receiver<{}>");
+// 145 is `Cow`.
 
 #[cfg(feature = "value")]
 macro_rules! impl_binary_container {
     ($t:ident => $node:ident,$id:ty,$vid:literal,$label:literal) => {
+        impl_binary_container!([T, U] $t<T, U> => $node, $id, $vid, $label);
+    };
+    ([$($generics:tt)*] $t:ty => $node:ident,$id:ty,$vid:literal,$label:literal) => {
         pub struct $node<T, U>(PhantomData<T>, PhantomData<U>);
         impl<T, U> Value<NodeKind> for $node<T, U>
         where
@@ -132,38 +214,35 @@ macro_rules! impl_binary_container {
             T: NodeOutputData,
             U: NodeOutputData,
         {
-            const ID: u32 = <T as NodeOutputData>::ID + <U as NodeOutputData>::ID + $vid;
+            const ID: u32 = $crate::instance_id(
+                $vid,
+                &[<T as NodeOutputData>::ID, <U as NodeOutputData>::ID],
+            );
             const KIND: NodeOutputKind = NodeOutputKind::BinaryContainer;
             const NAME: &'static str = stringify!($n<T>);
         }
-        impl<T, U> Typegraph for $t<T, U>
+        impl<$($generics)*> Typegraph for $t
         where
             T: Typegraph,
             U: Typegraph,
-            <T as Typegraph>::Id: Add<<U as Typegraph>::Id>,
-            <<T as Typegraph>::Id as Add<<U as Typegraph>::Id>>::Output: Add<$id>,
-            Edge<
-                <<<T as Typegraph>::Id as Add<<U as Typegraph>::Id>>::Output as Add<$id>>::Output,
-                <U as Typegraph>::Id
-            >: Equality<Edge<<<<T as Typegraph>::Id as Add<<U as Typegraph>::Id>>::Output as Add<$id>>::Output, <T as Typegraph>::Id>>,
-            (<Edge<
-                <<<T as Typegraph>::Id as Add<<U as Typegraph>::Id>>::Output as Add<$id>>::Output,
-                <U as Typegraph>::Id
-            > as Equality<Edge<<<<T as Typegraph>::Id as Add<<U as Typegraph>::Id>>::Output as Add<$id>>::Output, <T as Typegraph>::Id>>>::Out, False): Or,
-            <(<Edge<
-                <<<T as Typegraph>::Id as Add<<U as Typegraph>::Id>>::Output as Add<$id>>::Output,
-                <U as Typegraph>::Id
-            > as Equality<Edge<<<<T as Typegraph>::Id as Add<<U as Typegraph>::Id>>::Output as Add<$id>>::Output, <T as Typegraph>::Id>>>::Out, False) as Or>::Out: Falsy
+            <T as Typegraph>::Id: Equality<<U as Typegraph>::Id>,
+            <<T as Typegraph>::Id as Equality<<U as Typegraph>::Id>>::Out: Select<
+                set![TypedEdge<InstanceId<$id, ParamIds<<T as Typegraph>::Id, ParamIds<<U as Typegraph>::Id, NoParams>>>, <T as Typegraph>::Id, edge::Contains>],
+                set![TypedEdge<InstanceId<$id, ParamIds<<T as Typegraph>::Id, ParamIds<<U as Typegraph>::Id, NoParams>>>, <T as Typegraph>::Id, edge::Contains>, TypedEdge<InstanceId<$id, ParamIds<<T as Typegraph>::Id, ParamIds<<U as Typegraph>::Id, NoParams>>>, <U as Typegraph>::Id, edge::Contains>],
+            >,
         {
-            type Id = <<<T as Typegraph>::Id as Add<<U as Typegraph>::Id>>::Output as Add<$id>>::Output;
+            type Id = InstanceId<
+                $id,
+                ParamIds<<T as Typegraph>::Id, ParamIds<<U as Typegraph>::Id, NoParams>>,
+            >;
             type Node = $node<<T as Typegraph>::Node, <U as Typegraph>::Node>;
             type Nodes = set![
                 NodeOutput<Self::Id, $node<<T as Typegraph>::Node, <U as Typegraph>::Node>, $node<<T as Typegraph>::Node, <U as Typegraph>::Node>>,
             ];
-            type Edges = set![
-                Edge<Self::Id, <T as Typegraph>::Id>,
-                Edge<Self::Id, <U as Typegraph>::Id>
-            ];
+            type Edges = <<<T as Typegraph>::Id as Equality<<U as Typegraph>::Id>>::Out as Select<
+                set![TypedEdge<Self::Id, <T as Typegraph>::Id, edge::Contains>],
+                set![TypedEdge<Self::Id, <T as Typegraph>::Id, edge::Contains>, TypedEdge<Self::Id, <U as Typegraph>::Id, edge::Contains>],
+            >>::Out;
             type Types = list![T, U];
         }
     };
@@ -171,136 +250,180 @@ macro_rules! impl_binary_container {
 #[cfg(not(feature = "value"))]
 macro_rules! impl_binary_container {
     ($t:ident => $node:ident,$id:ty,$vid:literal,$label:literal) => {
+        impl_binary_container!([T, U] $t<T, U> => $node, $id, $vid, $label);
+    };
+    ([$($generics:tt)*] $t:ty => $node:ident,$id:ty,$vid:literal,$label:literal) => {
         pub struct $node<T, U>(PhantomData<T>, PhantomData<U>);
         impl<T, U> NodeOutputData for $node<T, U>
         where
             T: NodeOutputData,
             U: NodeOutputData,
         {
-            const ID: u32 = <T as NodeOutputData>::ID + <U as NodeOutputData>::ID + $vid;
+            const ID: u32 = $crate::instance_id(
+                $vid,
+                &[<T as NodeOutputData>::ID, <U as NodeOutputData>::ID],
+            );
             const KIND: NodeOutputKind = NodeOutputKind::BinaryContainer;
             const NAME: &'static str = stringify!($n<T>);
         }
-        impl<T, U> Typegraph for $t<T, U>
+        impl<$($generics)*> Typegraph for $t
         where
             T: Typegraph,
             U: Typegraph,
-            <T as Typegraph>::Id: Add<<U as Typegraph>::Id>,
-            <<T as Typegraph>::Id as Add<<U as Typegraph>::Id>>::Output: Add<$id>,
-            Edge<
-                <<<T as Typegraph>::Id as Add<<U as Typegraph>::Id>>::Output as Add<$id>>::Output,
-                <U as Typegraph>::Id
-            >: Equality<Edge<<<<T as Typegraph>::Id as Add<<U as Typegraph>::Id>>::Output as Add<$id>>::Output, <T as Typegraph>::Id>>,
-            (<Edge<
-                <<<T as Typegraph>::Id as Add<<U as Typegraph>::Id>>::Output as Add<$id>>::Output,
-                <U as Typegraph>::Id
-            > as Equality<Edge<<<<T as Typegraph>::Id as Add<<U as Typegraph>::Id>>::Output as Add<$id>>::Output, <T as Typegraph>::Id>>>::Out, False): Or,
-            <(<Edge<
-                <<<T as Typegraph>::Id as Add<<U as Typegraph>::Id>>::Output as Add<$id>>::Output,
-                <U as Typegraph>::Id
-            > as Equality<Edge<<<<T as Typegraph>::Id as Add<<U as Typegraph>::Id>>::Output as Add<$id>>::Output, <T as Typegraph>::Id>>>::Out, False) as Or>::Out: Falsy
+            <T as Typegraph>::Id: Equality<<U as Typegraph>::Id>,
+            <<T as Typegraph>::Id as Equality<<U as Typegraph>::Id>>::Out: Select<
+                set![TypedEdge<InstanceId<$id, ParamIds<<T as Typegraph>::Id, ParamIds<<U as Typegraph>::Id, NoParams>>>, <T as Typegraph>::Id, edge::Contains>],
+                set![TypedEdge<InstanceId<$id, ParamIds<<T as Typegraph>::Id, ParamIds<<U as Typegraph>::Id, NoParams>>>, <T as Typegraph>::Id, edge::Contains>, TypedEdge<InstanceId<$id, ParamIds<<T as Typegraph>::Id, ParamIds<<U as Typegraph>::Id, NoParams>>>, <U as Typegraph>::Id, edge::Contains>],
+            >,
         {
-            type Id = <<<T as Typegraph>::Id as Add<<U as Typegraph>::Id>>::Output as Add<$id>>::Output;
+            type Id = InstanceId<
+                $id,
+                ParamIds<<T as Typegraph>::Id, ParamIds<<U as Typegraph>::Id, NoParams>>,
+            >;
             type Node = $node<<T as Typegraph>::Node, <U as Typegraph>::Node>;
             type Nodes = set![
                 NodeOutput<Self::Id, $node<<T as Typegraph>::Node, <U as Typegraph>::Node>, $node<<T as Typegraph>::Node, <U as Typegraph>::Node>>,
             ];
-            type Edges = set![
-                Edge<Self::Id, <T as Typegraph>::Id>,
-                Edge<Self::Id, <U as Typegraph>::Id>
-            ];
+            type Edges = <<<T as Typegraph>::Id as Equality<<U as Typegraph>::Id>>::Out as Select<
+                set![TypedEdge<Self::Id, <T as Typegraph>::Id, edge::Contains>],
+                set![TypedEdge<Self::Id, <T as Typegraph>::Id, edge::Contains>, TypedEdge<Self::Id, <U as Typegraph>::Id, edge::Contains>],
+            >>::Out;
             type Types = list![T, U];
         }
     };
 }
 
 impl_binary_container!(Result => ResultNode, U200, 200, "Result<{}, {}>");
+#[cfg(feature = "std")]
 impl_binary_container!(HashMap => HashMapNode, U201, 201, "HashMap<{}, {}>");
+// 202 is tuples.
+#[cfg(feature = "alloc")]
+impl_binary_container!(BTreeMap => BTreeMapNode, U203, 203, "BTreeMap<{}, {}>");
 
-#[cfg(feature = "value")]
-impl<T, U> Value<NodeKind> for (T, U)
-where
-    T: Value<NodeKind>,
-    U: Value<NodeKind>,
-{
-    fn value() -> NodeKind {
-        let t_cluster = <T as Value<NodeKind>>::value().cluster();
-        let u_cluster = <U as Value<NodeKind>>::value().cluster();
-        NodeKind::BinaryContainer(
-            format!(
-                "({}, {})",
-                <T as Value<NodeKind>>::value().label(),
-                <U as Value<NodeKind>>::value().label()
-            )
-            .leak(),
-            if t_cluster.is_empty() {
-                u_cluster
-            } else {
-                t_cluster
-            },
-        )
-    }
-}
-impl<T, U> NodeOutputData for (T, U)
-where
-    T: NodeOutputData,
-    U: NodeOutputData,
-{
-    const ID: u32 = <T as NodeOutputData>::ID + <U as NodeOutputData>::ID + 202;
-    const KIND: NodeOutputKind = NodeOutputKind::BinaryContainer;
-    const NAME: &'static str = stringify!($n<T>);
+// Third-party containers are allocated unary IDs from 150 and binary IDs from 250.
+#[cfg(feature = "smallvec")]
+impl_unary_container!([T: smallvec::Array] smallvec::SmallVec<T> => SmallVecNode, U150, 150, "SmallVec<{}>");
+#[cfg(feature = "arrayvec")]
+impl_unary_container!([T, const CAP: usize] arrayvec::ArrayVec<T, CAP> => ArrayVecNode, U151, 151, "ArrayVec<{}>");
+#[cfg(feature = "hashbrown")]
+impl_unary_container!([T] hashbrown::HashSet<T> => HashbrownSetNode, U152, 152, "hashbrown::HashSet<{}>");
+#[cfg(feature = "indexmap")]
+impl_unary_container!([T] indexmap::IndexSet<T> => IndexSetNode, U153, 153, "IndexSet<{}>");
+#[cfg(feature = "chrono")]
+impl_unary_container!([T: chrono::TimeZone] chrono::DateTime<T> => DateTimeNode, U154, 154, "DateTime<{}>");
+#[cfg(feature = "tokio")]
+impl_unary_container!([T] tokio::sync::Mutex<T> => TokioMutexNode, U155, 155, "tokio::Mutex<{}>");
+#[cfg(feature = "tokio")]
+impl_unary_container!([T] tokio::sync::RwLock<T> => TokioRwLockNode, U156, 156, "tokio::RwLock<{}>");
+#[cfg(feature = "tokio")]
+impl_unary_container!([T] tokio::sync::OnceCell<T> => TokioOnceCellNode, U157, 157, "tokio::OnceCell<{}>");
+#[cfg(feature = "tokio")]
+impl_unary_container!([T] tokio::sync::mpsc::Sender<T> => TokioSenderNode, U158, 158, "mpsc::Sender<{}>");
+#[cfg(feature = "tokio")]
+impl_unary_container!([T] tokio::sync::mpsc::Receiver<T> => TokioReceiverNode, U159, 159, "mpsc::Receiver<{}>");
+#[cfg(feature = "tokio")]
+impl_unary_container!([T] tokio::sync::mpsc::UnboundedSender<T> => TokioUnboundedSenderNode, U160, 160, "mpsc::UnboundedSender<{}>");
+#[cfg(feature = "tokio")]
+impl_unary_container!([T] tokio::sync::mpsc::UnboundedReceiver<T> => TokioUnboundedReceiverNode, U161, 161, "mpsc::UnboundedReceiver<{}>");
+#[cfg(feature = "tokio")]
+impl_unary_container!([T] tokio::sync::oneshot::Sender<T> => OneshotSenderNode, U162, 162, "oneshot::Sender<{}>");
+#[cfg(feature = "tokio")]
+impl_unary_container!([T] tokio::sync::oneshot::Receiver<T> => OneshotReceiverNode, U163, 163, "oneshot::Receiver<{}>");
+#[cfg(feature = "tokio")]
+impl_unary_container!([T] tokio::sync::watch::Sender<T> => WatchSenderNode, U164, 164, "watch::Sender<{}>");
+#[cfg(feature = "tokio")]
+impl_unary_container!([T] tokio::sync::watch::Receiver<T> => WatchReceiverNode, U165, 165, "watch::Receiver<{}>");
+#[cfg(feature = "tokio")]
+impl_unary_container!([T] tokio::sync::broadcast::Sender<T> => BroadcastSenderNode, U166, 166, "broadcast::Sender<{}>");
+#[cfg(feature = "tokio")]
+impl_unary_container!([T] tokio::sync::broadcast::Receiver<T> => BroadcastReceiverNode, U167, 167, "broadcast::Receiver<{}>");
+#[cfg(feature = "tokio")]
+impl_unary_container!([T] tokio::task::JoinHandle<T> => TokioJoinHandleNode, U168, 168, "tokio::JoinHandle<{}>");
+#[cfg(feature = "hashbrown")]
+impl_binary_container!([T, U] hashbrown::HashMap<T, U> => HashbrownMapNode, U250, 250, "hashbrown::HashMap<{}, {}>");
+#[cfg(feature = "indexmap")]
+impl_binary_container!([T, U] indexmap::IndexMap<T, U> => IndexMapNode, U251, 251, "IndexMap<{}, {}>");
+#[cfg(feature = "serde_json")]
+impl_binary_container!([T, U] serde_json::Map<T, U> => JsonMapNode, U252, 252, "serde_json::Map<{}, {}>");
+
+/// Nested `ParamIds` of the IDs of the given types.
+macro_rules! param_ids {
+    () => { NoParams };
+    ($t:ident $(, $rest:ident)*) => {
+        ParamIds<<$t as Typegraph>::Id, param_ids!($($rest),*)>
+    };
 }
-impl<T, U> Typegraph for (T, U)
-where
-    T: Typegraph,
-    U: Typegraph,
-    <T as Typegraph>::Id: Add<<U as Typegraph>::Id>,
-    <<T as Typegraph>::Id as Add<<U as Typegraph>::Id>>::Output: Add<U202>,
-    Edge<
-        <<<T as Typegraph>::Id as Add<<U as Typegraph>::Id>>::Output as Add<U202>>::Output,
-        <U as Typegraph>::Id,
-    >: Equality<
-        Edge<
-            <<<T as Typegraph>::Id as Add<<U as Typegraph>::Id>>::Output as Add<U202>>::Output,
-            <T as Typegraph>::Id,
-        >,
-    >,
-    (
-        <Edge<
-            <<<T as Typegraph>::Id as Add<<U as Typegraph>::Id>>::Output as Add<U202>>::Output,
-            <U as Typegraph>::Id,
-        > as Equality<
-            Edge<
-                <<<T as Typegraph>::Id as Add<<U as Typegraph>::Id>>::Output as Add<U202>>::Output,
-                <T as Typegraph>::Id,
-            >,
-        >>::Out,
-        False,
-    ): Or,
-    <(
-        <Edge<
-            <<<T as Typegraph>::Id as Add<<U as Typegraph>::Id>>::Output as Add<U202>>::Output,
-            <U as Typegraph>::Id,
-        > as Equality<
-            Edge<
-                <<<T as Typegraph>::Id as Add<<U as Typegraph>::Id>>::Output as Add<U202>>::Output,
-                <T as Typegraph>::Id,
-            >,
-        >>::Out,
-        False,
-    ) as Or>::Out: Falsy,
-{
-    type Id = <<<T as Typegraph>::Id as Add<<U as Typegraph>::Id>>::Output as Add<U202>>::Output;
-    type Node = (<T as Typegraph>::Node, <U as Typegraph>::Node);
-    type Nodes = set![
-        NodeOutput <Self::Id,
-            (<T as Typegraph>::Node, <U as Typegraph>::Node),
-            (<T as Typegraph>::Node, <U as Typegraph>::Node)
-        >
-    ];
-    type Edges = set![
-        Edge<Self::Id, <T as Typegraph>::Id>,
-        Edge<Self::Id, <U as Typegraph>::Id>
-    ];
-    type Types = list![T, U];
+
+/// Tuples are a single node, with an edge to each of their element types labeled by position.
+macro_rules! impl_tuple {
+    ($($n:tt $t:ident),+) => {
+        #[cfg(feature = "value")]
+        impl<$($t),+> Value<NodeKind> for ($($t,)+)
+        where
+            $($t: Value<NodeKind> + NodeOutputData),+
+        {
+            fn value() -> NodeKind {
+                let elements = [$((
+                    <$t as Value<NodeKind>>::value(),
+                    <$t as NodeOutputData>::ID,
+                    concat!(".", stringify!($n)),
+                )),+];
+                let label = format!(
+                    "({}{})",
+                    elements
+                        .iter()
+                        .map(|(kind, _, _)| kind.label())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    if elements.len() == 1 { "," } else { "" }
+                );
+                let cluster = elements
+                    .iter()
+                    .map(|(kind, _, _)| kind.cluster())
+                    .find(|cluster| !cluster.is_empty())
+                    .unwrap_or(&[]);
+                // Positions of the same type share an edge, and so a label.
+                let mut fields: Vec<(&'static str, u32)> = Vec::new();
+                for (_, id, position) in elements {
+                    match fields.iter_mut().find(|(_, field_id)| *field_id == id) {
+                        Some((label, _)) => *label = format!("{label}, {position}").leak(),
+                        None => fields.push((position, id)),
+                    }
+                }
+
+                NodeKind::Tuple(label.leak(), cluster, fields.leak())
+            }
+        }
+        impl<$($t),+> NodeOutputData for ($($t,)+)
+        where
+            $($t: NodeOutputData),+
+        {
+            const ID: u32 = crate::instance_id(202, &[$(<$t as NodeOutputData>::ID),+]);
+            const KIND: NodeOutputKind = NodeOutputKind::Tuple;
+            const NAME: &'static str = stringify!(($($t,)+));
+        }
+        impl<$($t),+> Typegraph for ($($t,)+)
+        where
+            $($t: Typegraph),+
+        {
+            type Id = InstanceId<U202, param_ids!($($t),+)>;
+            type Node = ($(<$t as Typegraph>::Node,)+);
+            type Nodes = set![NodeOutput<Self::Id, Self::Node, Self::Node>];
+            type Edges = set![$(TypedEdge<Self::Id, <$t as Typegraph>::Id, edge::PropertyOf>),+];
+            type Types = list![$($t),+];
+        }
+    };
 }
+
+impl_tuple!(0 A);
+impl_tuple!(0 A, 1 B);
+impl_tuple!(0 A, 1 B, 2 C);
+impl_tuple!(0 A, 1 B, 2 C, 3 D);
+impl_tuple!(0 A, 1 B, 2 C, 3 D, 4 E);
+impl_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F);
+impl_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G);
+impl_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H);
+impl_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I);
+impl_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J);
+impl_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K);
+impl_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K, 11 L);
@@ -1,37 +1,76 @@
 use core::marker::PhantomData;
 
-use typosaurus::bool::And;
+use typosaurus::bool::{And, True};
 use typosaurus::traits::semigroup::MappendG;
-use typosaurus::{bool::False, cmp::Equality, num::UInt};
+use typosaurus::{bool::False, cmp::Equality, num::UInt, num::UTerm};
 
 pub trait NodeOutputData {
     const ID: u32;
     const KIND: NodeOutputKind;
     const NAME: &str;
+    /// The path the ID is derived from, like `type my_crate::Foo`, for the nodes of annotated
+    /// items. Primitives and containers, whose IDs don't come from a path, leave it empty.
+    const PATH: &str = "";
 }
 
 #[derive(Debug, Clone, Copy)]
 pub enum NodeOutputKind {
     Struct,
     Enum,
+    Union,
     Function,
     AsyncFunction,
     Implementation,
+    TraitImplementation,
+    Trait,
+    Field,
     Primitive,
     Variant,
     UnaryContainer,
     BinaryContainer,
+    Tuple,
     Generic,
+    Alias,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EdgeOutputKind {
+    /// Implementation or trait -> method.
     MethodOf,
+    /// Implementation or trait -> async method.
+    AsyncMethodOf,
+    /// Struct, union, variant or tuple -> type of one of its fields.
     PropertyOf,
+    /// Enum -> variant.
+    VariantOf,
+    /// Struct or union -> field node.
+    FieldOf,
+    /// Container -> contained type.
+    Contains,
+    /// Function -> function.
     Invokes,
-    Accesses,
+    /// Field -> function for reads, function -> field for writes.
+    Accesses(Access),
+    /// Type -> implementation.
     ImplementedBy,
+    /// Trait implementation -> trait.
+    Implements,
+    /// Argument type -> function.
+    ArgumentOf,
+    /// Function -> return type.
     Provides,
+    /// Generic parameter or trait -> trait it is bounded by.
+    Bound,
+    /// Implementation or trait -> type of an associated type or const.
+    Associates,
+    /// Type alias -> aliased type.
+    Aliases,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Access {
+    Read,
+    Write,
 }
 
 pub trait EdgeOutputData {
@@ -40,6 +79,60 @@ pub trait EdgeOutputData {
     const KIND: EdgeOutputKind;
 }
 
+/// Type-level counterparts of [`EdgeOutputKind`].
+pub mod edge {
+    use super::{Access, EdgeOutputKind};
+
+    pub trait Kind {
+        const KIND: EdgeOutputKind;
+    }
+
+    macro_rules! edge_kind {
+        ($($name:ident => $kind:expr),* $(,)?) => {
+            $(
+                pub struct $name;
+                impl Kind for $name {
+                    const KIND: EdgeOutputKind = $kind;
+                }
+            )*
+        };
+    }
+    edge_kind! {
+        MethodOf => EdgeOutputKind::MethodOf,
+        AsyncMethodOf => EdgeOutputKind::AsyncMethodOf,
+        PropertyOf => EdgeOutputKind::PropertyOf,
+        VariantOf => EdgeOutputKind::VariantOf,
+        FieldOf => EdgeOutputKind::FieldOf,
+        Contains => EdgeOutputKind::Contains,
+        Invokes => EdgeOutputKind::Invokes,
+        Reads => EdgeOutputKind::Accesses(Access::Read),
+        Writes => EdgeOutputKind::Accesses(Access::Write),
+        ImplementedBy => EdgeOutputKind::ImplementedBy,
+        Implements => EdgeOutputKind::Implements,
+        ArgumentOf => EdgeOutputKind::ArgumentOf,
+        Provides => EdgeOutputKind::Provides,
+        Bound => EdgeOutputKind::Bound,
+        Associates => EdgeOutputKind::Associates,
+        Aliases => EdgeOutputKind::Aliases,
+    }
+}
+
+/// The data of an edge of kind `Kind` (one of the types in [`edge`]) from `From` to `To`.
+pub struct EdgeData<From, To, Kind>(PhantomData<From>, PhantomData<To>, PhantomData<Kind>);
+impl<From, To, Kind> EdgeOutputData for EdgeData<From, To, Kind>
+where
+    From: Unsigned,
+    To: Unsigned,
+    Kind: edge::Kind,
+{
+    const FROM: u32 = <From as Unsigned>::U32;
+    const TO: u32 = <To as Unsigned>::U32;
+    const KIND: EdgeOutputKind = <Kind as edge::Kind>::KIND;
+}
+
+/// An edge as emitted by `Typegraph::Edges`, identified by its endpoints.
+pub type TypedEdge<From, To, Kind> = EdgeOutput<Edge<From, To>, EdgeData<From, To, Kind>>;
+
 pub trait Index {
     type Fields;
     type Methods;
@@ -53,6 +146,68 @@ pub trait Implementation {
 pub trait ImplementorRef {
     type Ref;
 }
+/// Looks up the node of an inherent `#[typegraph]` method or associated function by the hashed
+/// name of the function, for call edges.
+pub trait MethodNode<Name> {
+    type Id;
+}
+/// Implemented by the derive for every impl id listed in `implementations = [...]`, keyed by the
+/// hashed id. Each `#[typegraph(id = X)]` impl requires it of its self type, so an impl that the
+/// type doesn't list fails to compile instead of silently missing from the graph:
+///
+/// ```compile_fail,E0277
+/// use typegraph::{typegraph, Typegraph};
+///
+/// #[typegraph(implementations = [A])]
+/// pub struct Roost;
+///
+/// #[typegraph(id = A)]
+/// impl Roost {
+///     pub fn perch(&self) {}
+/// }
+///
+/// #[typegraph(id = B)]
+/// impl Roost {
+///     pub fn preen(&self) {}
+/// }
+/// ```
+///
+/// Listing it fixes that:
+///
+/// ```
+/// use typegraph::{typegraph, Typegraph};
+///
+/// #[typegraph(implementations = [A, B])]
+/// pub struct Roost;
+///
+/// #[typegraph(id = A)]
+/// impl Roost {
+///     pub fn perch(&self) {}
+/// }
+///
+/// #[typegraph(id = B)]
+/// impl Roost {
+///     pub fn preen(&self) {}
+/// }
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` has a `#[typegraph(id = ...)]` impl that isn't listed in its `implementations`",
+    label = "this impl's id is missing from `#[typegraph(implementations = [...])]` on `{Self}`",
+    note = "impls only contribute to a type's graph when the type lists their ids"
+)]
+pub trait ListedImpl<Name> {}
+/// Looks up a field of a `#[typegraph]` struct by the hashed name of the field, for access edges.
+/// `Field` is the field's own node, which is only part of a graph once some method accesses it.
+/// Only types annotated with `trace_fields` implement it.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` has no field nodes for a `trace_fields` impl to access",
+    label = "`{Self}` needs `#[typegraph(trace_fields)]` for its impls to trace field accesses",
+    note = "field nodes are only generated for types that opt in with `trace_fields`"
+)]
+pub trait FieldNode<Name> {
+    type Id;
+    type Field;
+}
 
 pub struct NodeOutput<Id, Data, Meta>(PhantomData<Id>, PhantomData<Data>, PhantomData<Meta>);
 impl<Id1, D1, M1, Id2, D2, M2> Equality<NodeOutput<Id1, D1, M1>> for NodeOutput<Id2, D2, M2>
@@ -96,6 +251,9 @@ pub trait IntoEdge {
 impl<From, To> IntoEdge for Edge<From, To> {
     type Out = (From, To);
 }
+impl<From, To, Data> IntoEdge for EdgeOutput<Edge<From, To>, Data> {
+    type Out = (From, To);
+}
 pub trait IntoNode {
     type Out;
 }
@@ -150,6 +308,108 @@ impl Unsigned for typosaurus::collections::maybe::Nothing {
     const U32: u32 = 0;
 }
 
+/// Type-level ID of a generic instantiation: a tag identifying the type constructor applied to
+/// the IDs of its parameters, so that two instantiations share an ID only if they are the same type.
+pub struct InstanceId<Tag, Params>(PhantomData<Tag>, PhantomData<Params>);
+/// A type-level list of parameter IDs, terminated by [`NoParams`].
+pub struct ParamIds<Head, Tail>(PhantomData<Head>, PhantomData<Tail>);
+pub struct NoParams;
+
+impl<T1, P1, T2, P2> Equality<InstanceId<T2, P2>> for InstanceId<T1, P1>
+where
+    T1: Equality<T2>,
+    P1: Equality<P2>,
+    (<T1 as Equality<T2>>::Out, <P1 as Equality<P2>>::Out): And,
+{
+    type Out = <(<T1 as Equality<T2>>::Out, <P1 as Equality<P2>>::Out) as And>::Out;
+}
+impl<T, P, U, B> Equality<UInt<U, B>> for InstanceId<T, P> {
+    type Out = False;
+}
+impl<T, P> Equality<UTerm> for InstanceId<T, P> {
+    type Out = False;
+}
+impl<T, P, U, B> Equality<InstanceId<T, P>> for UInt<U, B> {
+    type Out = False;
+}
+impl<T, P> Equality<InstanceId<T, P>> for UTerm {
+    type Out = False;
+}
+impl<H1, T1, H2, T2> Equality<ParamIds<H2, T2>> for ParamIds<H1, T1>
+where
+    H1: Equality<H2>,
+    T1: Equality<T2>,
+    (<H1 as Equality<H2>>::Out, <T1 as Equality<T2>>::Out): And,
+{
+    type Out = <(<H1 as Equality<H2>>::Out, <T1 as Equality<T2>>::Out) as And>::Out;
+}
+impl<H, T> Equality<NoParams> for ParamIds<H, T> {
+    type Out = False;
+}
+impl<H, T> Equality<ParamIds<H, T>> for NoParams {
+    type Out = False;
+}
+impl Equality<NoParams> for NoParams {
+    type Out = True;
+}
+
+/// Value-level counterpart of [`InstanceId`], shared by `NodeOutputData::ID` of container nodes
+/// and `Unsigned::U32` of their IDs. The high bit is always set, keeping instantiations clear of
+/// the IDs assigned to named types.
+pub const fn instance_id(tag: u32, params: &[u32]) -> u32 {
+    let mut hash = NO_PARAMS_HASH;
+    let mut i = params.len();
+    while i > 0 {
+        i -= 1;
+        hash = mix(params[i], hash);
+    }
+
+    0x8000_0000 | mix(tag, hash)
+}
+
+const NO_PARAMS_HASH: u32 = 0x9e37_79b9;
+
+const fn mix(a: u32, b: u32) -> u32 {
+    let mut h = a ^ b.wrapping_mul(0x9e37_79b9).rotate_left(13);
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x85eb_ca6b);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xc2b2_ae35);
+    h ^ (h >> 16)
+}
+
+pub trait ParamHash {
+    const HASH: u32;
+}
+impl ParamHash for NoParams {
+    const HASH: u32 = NO_PARAMS_HASH;
+}
+impl<H, T> ParamHash for ParamIds<H, T>
+where
+    H: Unsigned,
+    T: ParamHash,
+{
+    const HASH: u32 = mix(<H as Unsigned>::U32, <T as ParamHash>::HASH);
+}
+impl<T, P> Unsigned for InstanceId<T, P>
+where
+    T: Unsigned,
+    P: ParamHash,
+{
+    const U32: u32 = 0x8000_0000 | mix(<T as Unsigned>::U32, <P as ParamHash>::HASH);
+}
+
+/// Chooses between two types based on a type-level boolean.
+pub trait Select<A, B> {
+    type Out;
+}
+impl<A, B> Select<A, B> for True {
+    type Out = A;
+}
+impl<A, B> Select<A, B> for False {
+    type Out = B;
+}
+
 /// A type that is also a graph.
 pub trait Typegraph {
     type Id;
@@ -1,21 +1,22 @@
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
-#[cfg(feature = "std")]
 mod container;
 mod graph;
 mod op;
+mod pointer;
 mod primitive;
-#[cfg(feature = "std")]
 mod standard;
 
 #[cfg(feature = "value")]
 mod value;
 
 pub use graph::*;
-pub use op::Resolve;
+pub use op::{Resolve, TypedGraph};
 pub use typegraph_macros::{typegraph, Typegraph};
 pub use typosaurus::bool::{False, True};
 pub use typosaurus::cmp::Equality;
@@ -37,7 +38,19 @@ pub mod num {
     pub use typosaurus::num::{UInt, UTerm};
 }
 
+#[cfg(feature = "cypher")]
+pub use value::cypher::Cypher;
+#[cfg(feature = "gexf")]
+pub use value::gexf::Gexf;
+#[cfg(feature = "graphml")]
+pub use value::graphml::GraphMl;
 #[cfg(feature = "graphviz")]
 pub use value::graphviz::Graphviz;
+#[cfg(feature = "mermaid")]
+pub use value::mermaid::Mermaid;
+#[cfg(feature = "plantuml")]
+pub use value::plantuml::PlantUml;
+#[cfg(feature = "serde")]
+pub use value::json;
 #[cfg(feature = "value")]
-pub use value::{NodeKind, Value, ValueGraph};
+pub use value::{EdgeKind, NodeKind, Value, ValueGraph};
@@ -1,4 +1,6 @@
-use typosaurus::collections::graph::{self, ContainsId, Graph};
+use core::marker::PhantomData;
+
+use typosaurus::collections::graph::{self, ContainsId};
 use typosaurus::collections::list::{self, List};
 use typosaurus::collections::maybe::{IfNot, Just, Nothing};
 use typosaurus::collections::record::GetEntryMaybe;
@@ -6,7 +8,14 @@ use typosaurus::collections::set::IntoList;
 
 use crate::graph::{Index, IntoEdge, IntoNode, NodeOutput, Typegraph};
 
-pub type Resolve<T> = <(graph::Empty, Just<List<(T, List<()>)>>) as TypeResolvable>::Out;
+pub type Resolve<T> = <(
+    TypedGraph<graph::Empty, list::Empty>,
+    Just<List<(T, List<()>)>>,
+) as TypeResolvable>::Out;
+
+/// A graph along with the data of its edges, which the graph itself only records as pairs of node
+/// IDs.
+pub struct TypedGraph<G, E>(PhantomData<G>, PhantomData<E>);
 
 pub trait FieldResolver {
     type Out;
@@ -32,81 +41,82 @@ where
 pub trait MaybeInsertNodes {
     type Out;
 }
-impl<N, I, O> MaybeInsertNodes for (Graph<N, I, O>, list::Empty) {
-    type Out = Graph<N, I, O>;
+impl<G, E> MaybeInsertNodes for (TypedGraph<G, E>, list::Empty) {
+    type Out = TypedGraph<G, E>;
 }
-impl<N, I, O> MaybeInsertNodes for (Graph<N, I, O>, Nothing) {
-    type Out = Graph<N, I, O>;
+impl<G, E> MaybeInsertNodes for (TypedGraph<G, E>, Nothing) {
+    type Out = TypedGraph<G, E>;
 }
-impl<N, I, O> MaybeInsertNodes for (Graph<N, I, O>, Just<list::Empty>) {
-    type Out = Graph<N, I, O>;
+impl<G, E> MaybeInsertNodes for (TypedGraph<G, E>, Just<list::Empty>) {
+    type Out = TypedGraph<G, E>;
 }
-impl<T, U, N, I, O> MaybeInsertNodes for (Graph<N, I, O>, Just<List<(T, U)>>)
+impl<T, U, G, E> MaybeInsertNodes for (TypedGraph<G, E>, Just<List<(T, U)>>)
 where
     T: IntoNode,
-    (Graph<N, I, O>, <T as IntoNode>::Out): graph::InsertNode,
+    (G, <T as IntoNode>::Out): graph::InsertNode,
     (
-        <(Graph<N, I, O>, <T as IntoNode>::Out) as graph::InsertNode>::Out,
+        TypedGraph<<(G, <T as IntoNode>::Out) as graph::InsertNode>::Out, E>,
         U,
     ): MaybeInsertNodes,
 {
     type Out = <(
-        <(Graph<N, I, O>, <T as IntoNode>::Out) as graph::InsertNode>::Out,
+        TypedGraph<<(G, <T as IntoNode>::Out) as graph::InsertNode>::Out, E>,
         U,
     ) as MaybeInsertNodes>::Out;
 }
-impl<T, U, N, I, O> MaybeInsertNodes for (Graph<N, I, O>, List<(T, U)>)
+impl<T, U, G, E> MaybeInsertNodes for (TypedGraph<G, E>, List<(T, U)>)
 where
     T: IntoNode,
-    (Graph<N, I, O>, <T as IntoNode>::Out): graph::InsertNode,
+    (G, <T as IntoNode>::Out): graph::InsertNode,
     (
-        <(Graph<N, I, O>, <T as IntoNode>::Out) as graph::InsertNode>::Out,
+        TypedGraph<<(G, <T as IntoNode>::Out) as graph::InsertNode>::Out, E>,
         U,
     ): MaybeInsertNodes,
 {
     type Out = <(
-        <(Graph<N, I, O>, <T as IntoNode>::Out) as graph::InsertNode>::Out,
+        TypedGraph<<(G, <T as IntoNode>::Out) as graph::InsertNode>::Out, E>,
         U,
     ) as MaybeInsertNodes>::Out;
 }
 
+/// Connects the nodes of each edge and records the edge's data alongside the graph.
 pub trait MaybeConnectNodes {
     type Out;
 }
-impl<N, I, O> MaybeConnectNodes for (Graph<N, I, O>, list::Empty) {
-    type Out = Graph<N, I, O>;
+impl<G, E> MaybeConnectNodes for (TypedGraph<G, E>, list::Empty) {
+    type Out = TypedGraph<G, E>;
 }
-impl<N, I, O> MaybeConnectNodes for (Graph<N, I, O>, Nothing) {
-    type Out = Graph<N, I, O>;
+impl<G, E> MaybeConnectNodes for (TypedGraph<G, E>, Nothing) {
+    type Out = TypedGraph<G, E>;
 }
-impl<N, I, O> MaybeConnectNodes for (Graph<N, I, O>, Just<List<()>>) {
-    type Out = Graph<N, I, O>;
+impl<G, E> MaybeConnectNodes for (TypedGraph<G, E>, Just<List<()>>) {
+    type Out = TypedGraph<G, E>;
 }
-impl<T, U, N, I, O> MaybeConnectNodes for (Graph<N, I, O>, Just<List<(T, U)>>)
+impl<T, U, G, E> MaybeConnectNodes for (TypedGraph<G, E>, Just<List<(T, U)>>)
 where
     T: IntoEdge,
-    (Graph<N, I, O>, <T as IntoEdge>::Out): graph::ConnectNodes,
+    (G, <T as IntoEdge>::Out): graph::ConnectNodes,
     (
-        <(Graph<N, I, O>, <T as IntoEdge>::Out) as graph::ConnectNodes>::Out,
+        TypedGraph<<(G, <T as IntoEdge>::Out) as graph::ConnectNodes>::Out, List<(T, E)>>,
         U,
     ): MaybeConnectNodes,
 {
     type Out = <(
-        <(Graph<N, I, O>, <T as IntoEdge>::Out) as graph::ConnectNodes>::Out,
+        TypedGraph<<(G, <T as IntoEdge>::Out) as graph::ConnectNodes>::Out, List<(T, E)>>,
         U,
     ) as MaybeConnectNodes>::Out;
 }
-impl<T, U, N, I, O> MaybeConnectNodes for (Graph<N, I, O>, List<(T, U)>)
+impl<T, U, G, E> MaybeConnectNodes for (TypedGraph<G, E>, List<(T, U)>)
 where
     T: IntoEdge,
-    (Graph<N, I, O>, <T as IntoEdge>::Out): graph::ConnectNodes,
+    (G, <T as IntoEdge>::Out): graph::ConnectNodes,
     (
-        <(Graph<N, I, O>, <T as IntoEdge>::Out) as graph::ConnectNodes>::Out,
+        TypedGraph<<(G, <T as IntoEdge>::Out) as graph::ConnectNodes>::Out, List<(T, E)>>,
         U,
     ): MaybeConnectNodes,
 {
     type Out = <(
-        <(Graph<N, I, O>, <T as IntoEdge>::Out) as graph::ConnectNodes>::Out,
+        TypedGraph<<(G, <T as IntoEdge>::Out) as graph::ConnectNodes>::Out, List<(T, E)>>,
         U,
     ) as MaybeConnectNodes>::Out;
 }
@@ -114,44 +124,44 @@ where
 pub trait TypeResolvable {
     type Out;
 }
-impl<N, I, O> TypeResolvable for (Graph<N, I, O>, Nothing) {
-    type Out = Graph<N, I, O>;
+impl<G, E> TypeResolvable for (TypedGraph<G, E>, Nothing) {
+    type Out = TypedGraph<G, E>;
 }
-impl<N, I, O> TypeResolvable for (Graph<N, I, O>, Just<List<()>>) {
-    type Out = Graph<N, I, O>;
+impl<G, E> TypeResolvable for (TypedGraph<G, E>, Just<List<()>>) {
+    type Out = TypedGraph<G, E>;
 }
-impl<T, U, N, I, O> TypeResolvable for (Graph<N, I, O>, Just<List<(T, U)>>)
+impl<T, U, G, E> TypeResolvable for (TypedGraph<G, E>, Just<List<(T, U)>>)
 where
     T: Typegraph,
     <T as Typegraph>::Nodes: IntoList,
     <T as Typegraph>::Edges: IntoList,
     NodeOutput<<T as Typegraph>::Id, <T as Typegraph>::Node, <T as Typegraph>::Node>: IntoNode,
-    (Graph<N, I, O>, <T as Typegraph>::Id): ContainsId,
+    (G, <T as Typegraph>::Id): ContainsId,
     (
         Just<<<T as Typegraph>::Nodes as IntoList>::Out>,
-        <(Graph<N, I, O>, <T as Typegraph>::Id) as ContainsId>::Out,
+        <(G, <T as Typegraph>::Id) as ContainsId>::Out,
     ): IfNot,
     (
         Just<<T as Typegraph>::Types>,
-        <(Graph<N, I, O>, <T as Typegraph>::Id) as ContainsId>::Out,
+        <(G, <T as Typegraph>::Id) as ContainsId>::Out,
     ): IfNot,
     (
         Just<<<T as Typegraph>::Edges as IntoList>::Out>,
-        <(Graph<N, I, O>, <T as Typegraph>::Id) as ContainsId>::Out,
+        <(G, <T as Typegraph>::Id) as ContainsId>::Out,
     ): IfNot,
     (
-        Graph<N, I, O>,
+        TypedGraph<G, E>,
         <(
             Just<<<T as Typegraph>::Nodes as IntoList>::Out>,
-            <(Graph<N, I, O>, <T as Typegraph>::Id) as ContainsId>::Out,
+            <(G, <T as Typegraph>::Id) as ContainsId>::Out,
         ) as IfNot>::Out,
     ): MaybeInsertNodes,
     (
         <(
-            Graph<N, I, O>,
+            TypedGraph<G, E>,
             <(
                 Just<<<T as Typegraph>::Nodes as IntoList>::Out>,
-                <(Graph<N, I, O>, <T as Typegraph>::Id) as ContainsId>::Out,
+                <(G, <T as Typegraph>::Id) as ContainsId>::Out,
             ) as IfNot>::Out,
         ) as MaybeInsertNodes>::Out,
         Just<U>,
@@ -159,39 +169,39 @@ where
     (
         <(
             <(
-                Graph<N, I, O>,
+                TypedGraph<G, E>,
                 <(
                     Just<<<T as Typegraph>::Nodes as IntoList>::Out>,
-                    <(Graph<N, I, O>, <T as Typegraph>::Id) as ContainsId>::Out,
+                    <(G, <T as Typegraph>::Id) as ContainsId>::Out,
                 ) as IfNot>::Out,
             ) as MaybeInsertNodes>::Out,
             Just<U>,
         ) as TypeResolvable>::Out,
         <(
             Just<<T as Typegraph>::Types>,
-            <(Graph<N, I, O>, <T as Typegraph>::Id) as ContainsId>::Out,
+            <(G, <T as Typegraph>::Id) as ContainsId>::Out,
         ) as IfNot>::Out,
     ): TypeResolvable,
     (
         <(
             <(
                 <(
-                    Graph<N, I, O>,
+                    TypedGraph<G, E>,
                     <(
                         Just<<<T as Typegraph>::Nodes as IntoList>::Out>,
-                        <(Graph<N, I, O>, <T as Typegraph>::Id) as ContainsId>::Out,
+                        <(G, <T as Typegraph>::Id) as ContainsId>::Out,
                     ) as IfNot>::Out,
                 ) as MaybeInsertNodes>::Out,
                 Just<U>,
             ) as TypeResolvable>::Out,
             <(
                 Just<<T as Typegraph>::Types>,
-                <(Graph<N, I, O>, <T as Typegraph>::Id) as ContainsId>::Out,
+                <(G, <T as Typegraph>::Id) as ContainsId>::Out,
             ) as IfNot>::Out,
         ) as TypeResolvable>::Out,
         <(
             Just<<<T as Typegraph>::Edges as IntoList>::Out>,
-            <(Graph<N, I, O>, <T as Typegraph>::Id) as ContainsId>::Out,
+            <(G, <T as Typegraph>::Id) as ContainsId>::Out,
         ) as IfNot>::Out,
     ): MaybeConnectNodes,
 {
@@ -199,22 +209,22 @@ where
         <(
             <(
                 <(
-                    Graph<N, I, O>,
+                    TypedGraph<G, E>,
                     <(
                         Just<<<T as Typegraph>::Nodes as IntoList>::Out>,
-                        <(Graph<N, I, O>, <T as Typegraph>::Id) as ContainsId>::Out,
+                        <(G, <T as Typegraph>::Id) as ContainsId>::Out,
                     ) as IfNot>::Out,
                 ) as MaybeInsertNodes>::Out,
                 Just<U>,
             ) as TypeResolvable>::Out,
             <(
                 Just<<T as Typegraph>::Types>,
-                <(Graph<N, I, O>, <T as Typegraph>::Id) as ContainsId>::Out,
+                <(G, <T as Typegraph>::Id) as ContainsId>::Out,
             ) as IfNot>::Out,
         ) as TypeResolvable>::Out,
         <(
             Just<<<T as Typegraph>::Edges as IntoList>::Out>,
-            <(Graph<N, I, O>, <T as Typegraph>::Id) as ContainsId>::Out,
+            <(G, <T as Typegraph>::Id) as ContainsId>::Out,
         ) as IfNot>::Out,
     ) as MaybeConnectNodes>::Out;
 }
@@ -0,0 +1,81 @@
+use core::marker::PhantomData;
+
+use typosaurus::num::consts::*;
+use typosaurus::{list, set};
+
+use crate::{
+    edge, InstanceId, NoParams, NodeOutput, NodeOutputData, NodeOutputKind, ParamIds, TypedEdge,
+    Typegraph,
+};
+#[cfg(feature = "value")]
+use crate::{NodeKind, Value};
+
+/// Stands in for a pointee in `Types`, which can only hold sized types.
+#[doc(hidden)]
+pub struct Pointee<T: ?Sized>(PhantomData<T>);
+impl<T> Typegraph for Pointee<T>
+where
+    T: Typegraph + ?Sized,
+{
+    type Id = <T as Typegraph>::Id;
+    type Node = <T as Typegraph>::Node;
+    type Nodes = <T as Typegraph>::Nodes;
+    type Edges = <T as Typegraph>::Edges;
+    type Types = <T as Typegraph>::Types;
+}
+
+/// References, raw pointers, slices and arrays are unary containers of their element type. The
+/// kind of borrow is kept in the label.
+macro_rules! impl_pointer_container {
+    ([$($generics:tt)*] $t:ty => $node:ident,$id:ty,$vid:literal,$label:literal) => {
+        pub struct $node<T>(PhantomData<T>);
+        #[cfg(feature = "value")]
+        impl<T> Value<NodeKind> for $node<T>
+        where
+            T: Value<NodeKind>,
+        {
+            fn value() -> NodeKind {
+                NodeKind::UnaryContainer(
+                    std::format!($label, <T as Value<NodeKind>>::value().label()).leak(),
+                    <T as Value<NodeKind>>::value().cluster(),
+                )
+            }
+        }
+        impl<T> NodeOutputData for $node<T>
+        where
+            T: NodeOutputData,
+        {
+            const ID: u32 = $crate::instance_id($vid, &[<T as NodeOutputData>::ID]);
+            const KIND: NodeOutputKind = NodeOutputKind::UnaryContainer;
+            const NAME: &'static str = stringify!($t);
+        }
+        impl<$($generics)*> Typegraph for $t
+        where
+            T: Typegraph,
+        {
+            type Id = InstanceId<$id, ParamIds<<T as Typegraph>::Id, NoParams>>;
+            type Node = $node<<T as Typegraph>::Node>;
+            type Nodes = set![NodeOutput<
+                Self::Id,
+                $node<<T as Typegraph>::Node>,
+                $node<<T as Typegraph>::Node>,
+            >];
+            type Edges = set![TypedEdge<Self::Id, <T as Typegraph>::Id, edge::Contains>];
+            type Types = list![Pointee<T>];
+        }
+    };
+}
+
+impl_pointer_container!(['a, T: ?Sized] &'a T => RefNode, U110, 110, "&{}");
+impl_pointer_container!(['a, T: ?Sized] &'a mut T => RefMutNode, U111, 111, "&mut {}");
+impl_pointer_container!([T: ?Sized] *const T => ConstPtrNode, U112, 112, "*const {}");
+impl_pointer_container!([T: ?Sized] *mut T => MutPtrNode, U113, 113, "*mut {}");
+impl_pointer_container!([T] [T] => SliceNode, U114, 114, "[{}]");
+// The length of an array can't be carried into its type-level ID, so arrays of the same element
+// type share a node.
+impl_pointer_container!([T, const N: usize] [T; N] => ArrayNode, U115, 115, "[{}; _]");
+
+#[cfg(feature = "alloc")]
+impl_pointer_container!(
+    ['a, T: ?Sized + alloc::borrow::ToOwned] alloc::borrow::Cow<'a, T> => CowNode, U145, 145, "Cow<{}>"
+);
@@ -65,23 +65,3 @@ primitive_typegraph_impl!(f64 => F64Node, U15);
 primitive_typegraph_impl!(char => CharNode, U17);
 primitive_typegraph_impl!(bool => BoolNode, U18);
 primitive_typegraph_impl!(str => StrNode, U19);
-
-pub struct StrSliceNode;
-#[cfg(feature = "value")]
-impl Value<NodeKind> for StrSliceNode {
-    fn value() -> NodeKind {
-        NodeKind::Primitive("&str", &[])
-    }
-}
-impl NodeOutputData for StrSliceNode {
-    const ID: u32 = 20;
-    const KIND: NodeOutputKind = NodeOutputKind::Primitive;
-    const NAME: &'static str = "&str";
-}
-impl Typegraph for &str {
-    type Id = U20;
-    type Node = StrSliceNode;
-    type Nodes = set![NodeOutput<U20, StrSliceNode, StrSliceNode>];
-    type Edges = set![];
-    type Types = list![];
-}
@@ -1,9 +1,39 @@
-use std::string::String;
-use std::sync::atomic::{AtomicBool, AtomicUsize};
-use std::sync::Condvar;
-use std::task::Waker;
-use std::thread::ThreadId;
-use std::time::Duration;
+//! Non-generic types from `core`, `alloc` and `std`, allocated IDs from 300.
+
+use core::ffi::CStr;
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+#[cfg(target_has_atomic = "8")]
+use core::sync::atomic::{AtomicBool, AtomicI8, AtomicU8};
+#[cfg(target_has_atomic = "16")]
+use core::sync::atomic::{AtomicI16, AtomicU16};
+#[cfg(target_has_atomic = "32")]
+use core::sync::atomic::{AtomicI32, AtomicU32};
+#[cfg(target_has_atomic = "64")]
+use core::sync::atomic::{AtomicI64, AtomicU64};
+#[cfg(target_has_atomic = "ptr")]
+use core::sync::atomic::{AtomicIsize, AtomicUsize};
+use core::task::Waker;
+use core::time::Duration;
+
+#[cfg(feature = "alloc")]
+use alloc::ffi::CString;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+#[cfg(feature = "std")]
+use std::ffi::{OsStr, OsString};
+#[cfg(feature = "std")]
+use std::path::{Path, PathBuf};
+#[cfg(feature = "std")]
+use std::sync::{Barrier, Condvar};
+#[cfg(feature = "std")]
+use std::thread::{Thread, ThreadId};
+#[cfg(feature = "std")]
+use std::time::{Instant, SystemTime};
 
 use typosaurus::num::consts::*;
 use typosaurus::{list, set};
@@ -56,9 +86,113 @@ macro_rules! standard_typegraph_impl {
 }
 
 standard_typegraph_impl!(Duration => DurationNode, U300);
+#[cfg(target_has_atomic = "8")]
 standard_typegraph_impl!(AtomicBool => AtomicBoolNode, U301);
+#[cfg(target_has_atomic = "ptr")]
 standard_typegraph_impl!(AtomicUsize => AtomicUsizeNode, U302);
+#[cfg(feature = "std")]
 standard_typegraph_impl!(Condvar => CondvarNode, U303);
+#[cfg(feature = "std")]
 standard_typegraph_impl!(ThreadId => ThreadIdNode, U304);
 standard_typegraph_impl!(Waker => WakerNode, U305);
+#[cfg(feature = "alloc")]
 standard_typegraph_impl!(String => StringNode, U306);
+#[cfg(feature = "std")]
+standard_typegraph_impl!(PathBuf => PathBufNode, U307);
+#[cfg(feature = "std")]
+standard_typegraph_impl!(Path => PathNode, U308);
+#[cfg(feature = "std")]
+standard_typegraph_impl!(OsString => OsStringNode, U309);
+#[cfg(feature = "std")]
+standard_typegraph_impl!(OsStr => OsStrNode, U310);
+#[cfg(feature = "alloc")]
+standard_typegraph_impl!(CString => CStringNode, U311);
+standard_typegraph_impl!(CStr => CStrNode, U312);
+#[cfg(feature = "std")]
+standard_typegraph_impl!(Instant => InstantNode, U313);
+#[cfg(feature = "std")]
+standard_typegraph_impl!(SystemTime => SystemTimeNode, U314);
+#[cfg(feature = "std")]
+standard_typegraph_impl!(Thread => ThreadNode, U315);
+#[cfg(feature = "std")]
+standard_typegraph_impl!(Barrier => BarrierNode, U316);
+#[cfg(target_has_atomic = "8")]
+standard_typegraph_impl!(AtomicU8 => AtomicU8Node, U317);
+#[cfg(target_has_atomic = "16")]
+standard_typegraph_impl!(AtomicU16 => AtomicU16Node, U318);
+#[cfg(target_has_atomic = "32")]
+standard_typegraph_impl!(AtomicU32 => AtomicU32Node, U319);
+#[cfg(target_has_atomic = "64")]
+standard_typegraph_impl!(AtomicU64 => AtomicU64Node, U320);
+#[cfg(target_has_atomic = "8")]
+standard_typegraph_impl!(AtomicI8 => AtomicI8Node, U321);
+#[cfg(target_has_atomic = "16")]
+standard_typegraph_impl!(AtomicI16 => AtomicI16Node, U322);
+#[cfg(target_has_atomic = "32")]
+standard_typegraph_impl!(AtomicI32 => AtomicI32Node, U323);
+#[cfg(target_has_atomic = "64")]
+standard_typegraph_impl!(AtomicI64 => AtomicI64Node, U324);
+#[cfg(target_has_atomic = "ptr")]
+standard_typegraph_impl!(AtomicIsize => AtomicIsizeNode, U325);
+standard_typegraph_impl!(NonZeroU8 => NonZeroU8Node, U326);
+standard_typegraph_impl!(NonZeroU16 => NonZeroU16Node, U327);
+standard_typegraph_impl!(NonZeroU32 => NonZeroU32Node, U328);
+standard_typegraph_impl!(NonZeroU64 => NonZeroU64Node, U329);
+standard_typegraph_impl!(NonZeroU128 => NonZeroU128Node, U330);
+standard_typegraph_impl!(NonZeroUsize => NonZeroUsizeNode, U331);
+standard_typegraph_impl!(NonZeroI8 => NonZeroI8Node, U332);
+standard_typegraph_impl!(NonZeroI16 => NonZeroI16Node, U333);
+standard_typegraph_impl!(NonZeroI32 => NonZeroI32Node, U334);
+standard_typegraph_impl!(NonZeroI64 => NonZeroI64Node, U335);
+standard_typegraph_impl!(NonZeroI128 => NonZeroI128Node, U336);
+standard_typegraph_impl!(NonZeroIsize => NonZeroIsizeNode, U337);
+standard_typegraph_impl!(IpAddr => IpAddrNode, U338);
+standard_typegraph_impl!(Ipv4Addr => Ipv4AddrNode, U339);
+standard_typegraph_impl!(Ipv6Addr => Ipv6AddrNode, U340);
+standard_typegraph_impl!(SocketAddr => SocketAddrNode, U341);
+standard_typegraph_impl!(SocketAddrV4 => SocketAddrV4Node, U342);
+standard_typegraph_impl!(SocketAddrV6 => SocketAddrV6Node, U343);
+
+// Third-party types are allocated IDs from 400.
+#[cfg(feature = "serde_json")]
+standard_typegraph_impl!(serde_json::Value => JsonValueNode, U400);
+#[cfg(feature = "serde_json")]
+standard_typegraph_impl!(serde_json::Number => JsonNumberNode, U401);
+#[cfg(feature = "uuid")]
+standard_typegraph_impl!(uuid::Uuid => UuidNode, U402);
+#[cfg(feature = "chrono")]
+standard_typegraph_impl!(chrono::NaiveDate => NaiveDateNode, U403);
+#[cfg(feature = "chrono")]
+standard_typegraph_impl!(chrono::NaiveTime => NaiveTimeNode, U404);
+#[cfg(feature = "chrono")]
+standard_typegraph_impl!(chrono::NaiveDateTime => NaiveDateTimeNode, U405);
+#[cfg(feature = "chrono")]
+standard_typegraph_impl!(chrono::Utc => UtcNode, U406);
+#[cfg(feature = "chrono")]
+standard_typegraph_impl!(chrono::FixedOffset => FixedOffsetNode, U407);
+#[cfg(feature = "chrono")]
+standard_typegraph_impl!(chrono::Local => LocalNode, U408);
+#[cfg(feature = "chrono")]
+standard_typegraph_impl!(chrono::TimeDelta => TimeDeltaNode, U409);
+#[cfg(feature = "time")]
+standard_typegraph_impl!(time::OffsetDateTime => OffsetDateTimeNode, U410);
+#[cfg(feature = "time")]
+standard_typegraph_impl!(time::PrimitiveDateTime => PrimitiveDateTimeNode, U411);
+#[cfg(feature = "time")]
+standard_typegraph_impl!(time::Date => DateNode, U412);
+#[cfg(feature = "time")]
+standard_typegraph_impl!(time::Time => TimeNode, U413);
+#[cfg(feature = "time")]
+standard_typegraph_impl!(time::Duration => TimeDurationNode, U414);
+#[cfg(feature = "time")]
+standard_typegraph_impl!(time::UtcOffset => UtcOffsetNode, U415);
+#[cfg(feature = "bytes")]
+standard_typegraph_impl!(bytes::Bytes => BytesNode, U416);
+#[cfg(feature = "bytes")]
+standard_typegraph_impl!(bytes::BytesMut => BytesMutNode, U417);
+#[cfg(feature = "tokio")]
+standard_typegraph_impl!(tokio::sync::Notify => NotifyNode, U418);
+#[cfg(feature = "tokio")]
+standard_typegraph_impl!(tokio::sync::Semaphore => SemaphoreNode, U419);
+#[cfg(feature = "tokio")]
+standard_typegraph_impl!(tokio::time::Instant => TokioInstantNode, U420);
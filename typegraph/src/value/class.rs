@@ -0,0 +1,176 @@
+use petgraph::graph::NodeIndex;
+use petgraph::visit::{EdgeRef, IntoNodeReferences};
+use std::collections::{HashMap, HashSet};
+
+use super::{EdgeKind, EdgeKindWithIxs, NodeKind};
+use crate::value::String;
+use crate::value::Vec;
+use std::string::ToString;
+use std::vec;
+
+/// A graph as a class diagram, shared by the class diagram exporters.
+///
+/// Impls, fields and methods are folded into the classes they belong to: types list their fields
+/// and the methods of their impls, and trait impls relate the trait to the implementing type.
+pub struct ClassDiagram {
+    pub classes: Vec<Class>,
+    pub relations: Vec<Relation>,
+}
+
+pub struct Class {
+    pub index: usize,
+    pub node: NodeKind,
+    pub fields: Vec<(&'static str, &'static str)>,
+    pub methods: Vec<Method>,
+}
+
+pub struct Method {
+    pub name: &'static str,
+    pub is_async: bool,
+    pub args: Vec<(&'static str, &'static str)>,
+    pub returns: Option<&'static str>,
+}
+
+pub struct Relation {
+    pub from: usize,
+    pub to: usize,
+    pub kind: RelationKind,
+    pub label: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RelationKind {
+    Composition,
+    Aggregation,
+    Inheritance,
+    Realization,
+    Dependency,
+    Association,
+}
+
+impl ClassDiagram {
+    pub fn new(graph: &petgraph::Graph<NodeKind, EdgeKindWithIxs<NodeIndex>>) -> Self {
+        // Metadata refers to nodes by ID, edges know both the IDs and indices of their endpoints.
+        let mut ixs = HashMap::new();
+        for e in graph.edge_weights() {
+            ixs.insert(e.from, e.from_ix);
+            ixs.insert(e.to, e.to_ix);
+        }
+        let label = |id: &u32| ixs.get(id).map(|ix| graph[*ix].label()).unwrap_or("_");
+
+        let mut hidden = graph
+            .node_references()
+            .filter(|(_, node)| {
+                matches!(
+                    node,
+                    NodeKind::Implementation(..)
+                        | NodeKind::TraitImplementation(..)
+                        | NodeKind::Field(..)
+                )
+            })
+            .map(|(ix, _)| ix)
+            .collect::<HashSet<_>>();
+        let mut self_types = HashMap::new();
+        let mut returns = HashMap::new();
+        for e in graph.edge_references() {
+            match e.weight().kind {
+                EdgeKind::Implementation => {
+                    self_types.insert(e.target(), e.source());
+                }
+                EdgeKind::Returns => {
+                    returns.insert(e.source(), graph[e.target()].label());
+                }
+                _ => {}
+            }
+        }
+        let method = |f: NodeIndex| Method {
+            name: graph[f].label(),
+            is_async: matches!(graph[f], NodeKind::AsyncFunction(..)),
+            args: graph[f]
+                .metadata()
+                .iter()
+                .map(|(arg, id)| (*arg, label(id)))
+                .collect(),
+            returns: returns.get(&f).copied(),
+        };
+        let mut methods: HashMap<NodeIndex, Vec<Method>> = HashMap::new();
+        for e in graph.edge_references() {
+            if let EdgeKind::Function | EdgeKind::AsyncFunction = e.weight().kind {
+                let (owner, f) = (
+                    *self_types.get(&e.source()).unwrap_or(&e.source()),
+                    e.target(),
+                );
+                methods.entry(owner).or_default().push(method(f));
+                hidden.insert(f);
+            }
+        }
+
+        let classes = graph
+            .node_references()
+            .filter(|(ix, _)| !hidden.contains(ix))
+            .map(|(ix, node)| Class {
+                index: ix.index(),
+                node: node.clone(),
+                fields: match node {
+                    NodeKind::Struct(_, _, v)
+                    | NodeKind::Union(_, _, v)
+                    | NodeKind::Variant(_, _, v)
+                    | NodeKind::Tuple(_, _, v) => {
+                        v.iter().map(|(name, id)| (*name, label(id))).collect()
+                    }
+                    _ => vec![],
+                },
+                // A free function is a class of its own, listing its signature.
+                methods: match node {
+                    NodeKind::Function(..) | NodeKind::AsyncFunction(..) => vec![method(ix)],
+                    _ => methods.remove(&ix).unwrap_or_default(),
+                },
+            })
+            .collect();
+
+        let mut relations = vec![];
+        for e in graph.edge_references() {
+            let (from, to, weight) = (e.source(), e.target(), e.weight());
+            let meta = weight.meta(graph);
+            // A trait impl relates the trait to the implementing type.
+            if let EdgeKind::Implements = weight.kind {
+                if let Some(ty) = self_types.get(&from).filter(|ty| !hidden.contains(*ty)) {
+                    relations.push(Relation {
+                        from: to.index(),
+                        to: ty.index(),
+                        kind: RelationKind::Realization,
+                        label: String::new(),
+                    });
+                }
+                continue;
+            }
+            if hidden.contains(&from) || hidden.contains(&to) {
+                continue;
+            }
+            let (kind, from, to) = match weight.kind {
+                EdgeKind::Property => (RelationKind::Composition, from, to),
+                EdgeKind::Contains => (RelationKind::Aggregation, from, to),
+                EdgeKind::Variant => (RelationKind::Inheritance, from, to),
+                EdgeKind::Bound => (RelationKind::Realization, to, from),
+                EdgeKind::Argument => (RelationKind::Dependency, to, from),
+                EdgeKind::Call | EdgeKind::Generic | EdgeKind::Alias => {
+                    (RelationKind::Dependency, from, to)
+                }
+                _ => (RelationKind::Association, from, to),
+            };
+            let label = match weight.kind {
+                EdgeKind::Property | EdgeKind::Argument | EdgeKind::Associated => meta,
+                EdgeKind::Contains | EdgeKind::Variant => "".to_string(),
+                _ => weight.label(&meta),
+            };
+            relations.push(Relation {
+                from: from.index(),
+                to: to.index(),
+                kind,
+                label,
+            });
+        }
+
+        Self { classes, relations }
+    }
+}
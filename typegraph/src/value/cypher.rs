@@ -0,0 +1,95 @@
+use super::{Node, NodeKind, NodePath, ValueGraph, Vectorize};
+use crate::value::format;
+use crate::value::String;
+use crate::value::Vec;
+use std::collections::BTreeMap;
+use std::string::ToString;
+
+/// Renders a graph as a Cypher script that loads it into Neo4j or Memgraph.
+///
+/// Nodes are merged as `:Typegraph` nodes, with a second label for their kind (`:Struct`,
+/// `:Trait`, ...) and `id`, `kind`, `label` and `clusters` properties. The nodes of annotated items
+/// are merged on their `path`, the crate-qualified path their type-level ID is derived from, since
+/// IDs are hashes and are only checked for collisions within a crate. Primitives and containers
+/// have no path and are merged on their ID. Relationships are merged on their endpoints and kind,
+/// with the kind as the relationship type (`PROPERTY`, `RETURNS`, ...) and a `label` property.
+/// Running the script again, or the scripts of several crates, leaves a single node per item and
+/// a single relationship per kind between two nodes.
+///
+/// Lookups are only fast with indexes on `:Typegraph(path)` and `:Typegraph(id)`, which the script
+/// leaves to the database, as index syntax differs between them.
+pub trait Cypher {
+    fn render() -> String;
+}
+impl<T> Cypher for T
+where
+    T: ValueGraph<NodeKind>,
+    <T as ValueGraph<NodeKind>>::NodeList: Vectorize<Node<NodeKind>> + Vectorize<NodePath>,
+{
+    fn render() -> String {
+        let graph = <Self as ValueGraph<NodeKind>>::value();
+        let ids = super::node_ids::<Self>();
+        let paths = super::node_paths::<Self>();
+
+        fn string(s: &str) -> String {
+            format!("'{}'", s.replace('\\', "\\\\").replace('\'', "\\'"))
+        }
+        // `unary_container` becomes `UnaryContainer`.
+        fn pascal_case(s: &str) -> String {
+            s.split('_')
+                .map(|w| {
+                    let mut chars = w.chars();
+                    chars
+                        .next()
+                        .map(|c| c.to_ascii_uppercase().to_string() + chars.as_str())
+                        .unwrap_or_default()
+                })
+                .collect()
+        }
+
+        // The properties a node is merged and matched on, by ID.
+        let keys = ids
+            .iter()
+            .zip(&paths)
+            .map(|(id, path)| {
+                let key = if path.is_empty() {
+                    format!("id: {id}")
+                } else {
+                    format!("path: {}", string(path))
+                };
+                (*id, key)
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        let nodes = graph.node_indices().map(|ix| {
+            let node = &graph[ix];
+            let id = ids[ix.index()];
+            let clusters = node
+                .cluster()
+                .iter()
+                .map(|c| string(c))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!(
+                "MERGE (n:Typegraph {{{}}}) SET n:{}, n.id = {}, n.kind = {}, n.label = {}, n.clusters = [{}];",
+                keys[&id],
+                pascal_case(node.name()),
+                id,
+                string(node.name()),
+                string(node.label()),
+                clusters,
+            )
+        });
+        let edges = graph.edge_weights().map(|e| {
+            format!(
+                "MATCH (a:Typegraph {{{}}}), (b:Typegraph {{{}}}) MERGE (a)-[r:{}]->(b) SET r.label = {};",
+                keys[&e.from],
+                keys[&e.to],
+                e.kind.name().to_ascii_uppercase(),
+                string(&e.label(&e.meta(&graph))),
+            )
+        });
+
+        nodes.chain(edges).collect::<Vec<_>>().join("\n")
+    }
+}
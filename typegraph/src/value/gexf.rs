@@ -0,0 +1,61 @@
+use super::{escape_xml, NodeKind, ValueGraph};
+use crate::value::format;
+use crate::value::String;
+use std::vec;
+
+/// Renders a graph as GEXF 1.3, for Gephi.
+///
+/// Nodes and edges are labeled, and carry their kind and, for nodes, their cluster path (joined
+/// by `::`) as attributes. Kinds are the snake case names of the [`NodeKind`] and
+/// [`super::EdgeKind`] variants.
+pub trait Gexf {
+    fn render() -> String;
+}
+impl<T> Gexf for T
+where
+    T: ValueGraph<NodeKind>,
+{
+    fn render() -> String {
+        let graph = <Self as ValueGraph<NodeKind>>::value();
+
+        let mut lines = vec![
+            r#"<?xml version="1.0" encoding="UTF-8"?>"#.into(),
+            r#"<gexf xmlns="http://gexf.net/1.3" version="1.3">"#.into(),
+            r#"  <graph defaultedgetype="directed" mode="static">"#.into(),
+            r#"    <attributes class="node">"#.into(),
+            r#"      <attribute id="kind" title="kind" type="string"/>"#.into(),
+            r#"      <attribute id="cluster" title="cluster" type="string"/>"#.into(),
+            r#"    </attributes>"#.into(),
+            r#"    <attributes class="edge">"#.into(),
+            r#"      <attribute id="kind" title="kind" type="string"/>"#.into(),
+            r#"    </attributes>"#.into(),
+            r#"    <nodes>"#.into(),
+        ];
+        for ix in graph.node_indices() {
+            let node = &graph[ix];
+            lines.push(format!(
+                "      <node id=\"{}\" label=\"{}\"><attvalues><attvalue for=\"kind\" value=\"{}\"/><attvalue for=\"cluster\" value=\"{}\"/></attvalues></node>",
+                ix.index(),
+                escape_xml(node.label()),
+                node.name(),
+                escape_xml(&node.cluster().join("::")),
+            ));
+        }
+        lines.push("    </nodes>".into());
+        lines.push("    <edges>".into());
+        for (i, e) in graph.edge_weights().enumerate() {
+            lines.push(format!(
+                "      <edge id=\"{i}\" source=\"{}\" target=\"{}\" label=\"{}\"><attvalues><attvalue for=\"kind\" value=\"{}\"/></attvalues></edge>",
+                e.from_ix.index(),
+                e.to_ix.index(),
+                escape_xml(&e.label(&e.meta(&graph))),
+                e.kind.name(),
+            ));
+        }
+        lines.push("    </edges>".into());
+        lines.push("  </graph>".into());
+        lines.push("</gexf>".into());
+
+        lines.join("\n")
+    }
+}
@@ -0,0 +1,54 @@
+use super::{escape_xml, NodeKind, ValueGraph};
+use crate::value::format;
+use crate::value::String;
+use std::vec;
+
+/// Renders a graph as GraphML, for tools like yEd and Cytoscape.
+///
+/// Nodes carry their label, kind and cluster path (joined by `::`) as data, and edges their label
+/// and kind. Kinds are the snake case names of the [`NodeKind`] and [`super::EdgeKind`] variants.
+pub trait GraphMl {
+    fn render() -> String;
+}
+impl<T> GraphMl for T
+where
+    T: ValueGraph<NodeKind>,
+{
+    fn render() -> String {
+        let graph = <Self as ValueGraph<NodeKind>>::value();
+
+        let mut lines = vec![
+            r#"<?xml version="1.0" encoding="UTF-8"?>"#.into(),
+            r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#.into(),
+            r#"  <key id="label" for="node" attr.name="label" attr.type="string"/>"#.into(),
+            r#"  <key id="kind" for="node" attr.name="kind" attr.type="string"/>"#.into(),
+            r#"  <key id="cluster" for="node" attr.name="cluster" attr.type="string"/>"#.into(),
+            r#"  <key id="edge_label" for="edge" attr.name="label" attr.type="string"/>"#.into(),
+            r#"  <key id="edge_kind" for="edge" attr.name="kind" attr.type="string"/>"#.into(),
+            r#"  <graph id="typegraph" edgedefault="directed">"#.into(),
+        ];
+        for ix in graph.node_indices() {
+            let node = &graph[ix];
+            lines.push(format!(
+                "    <node id=\"n{}\"><data key=\"label\">{}</data><data key=\"kind\">{}</data><data key=\"cluster\">{}</data></node>",
+                ix.index(),
+                escape_xml(node.label()),
+                node.name(),
+                escape_xml(&node.cluster().join("::")),
+            ));
+        }
+        for (i, e) in graph.edge_weights().enumerate() {
+            lines.push(format!(
+                "    <edge id=\"e{i}\" source=\"n{}\" target=\"n{}\"><data key=\"edge_label\">{}</data><data key=\"edge_kind\">{}</data></edge>",
+                e.from_ix.index(),
+                e.to_ix.index(),
+                escape_xml(&e.label(&e.meta(&graph))),
+                e.kind.name(),
+            ));
+        }
+        lines.push("  </graph>".into());
+        lines.push("</graphml>".into());
+
+        lines.join("\n")
+    }
+}
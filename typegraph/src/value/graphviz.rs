@@ -49,7 +49,11 @@ where
                             .get(&er.target())
                             .and_then(|x| x.get(&er.weight().from))
                             .unwrap_or(&&""),
-                        EdgeKind::Property => properties
+                        EdgeKind::Property
+                        | EdgeKind::Bound
+                        | EdgeKind::Associated
+                        | EdgeKind::Function
+                        | EdgeKind::AsyncFunction => properties
                             .get(&er.source())
                             .and_then(|x| x.get(&er.weight().to))
                             .unwrap_or(&&""),
@@ -188,7 +192,14 @@ where
         graph.node_references().for_each(|r| {
             cluster.register(r.weight().cluster());
             match r.weight() {
-                NodeKind::Variant(_, _, v) | NodeKind::Struct(_, _, v) => {
+                NodeKind::Variant(_, _, v)
+                | NodeKind::Struct(_, _, v)
+                | NodeKind::Union(_, _, v)
+                | NodeKind::Tuple(_, _, v)
+                | NodeKind::Generic(_, _, v)
+                | NodeKind::Implementation(_, _, v)
+                | NodeKind::TraitImplementation(_, _, _, v)
+                | NodeKind::Trait(_, _, v) => {
                     let props = v.iter().map(|(s, n)| (*n, s)).collect::<HashMap<_, _>>();
                     properties.insert(r.id(), props);
                 }
@@ -0,0 +1,131 @@
+//! A serializable form of a resolved graph, for tools that consume typegraph output as data.
+//!
+//! Serialized as JSON, a [`Document`] looks like:
+//!
+//! ```json
+//! {
+//!   "schema_version": 1,
+//!   "nodes": [
+//!     {
+//!       "id": 4211,
+//!       "kind": "struct",
+//!       "label": "struct Roost",
+//!       "clusters": ["aviary"],
+//!       "fields": [{ "name": "perches", "node": 2 }],
+//!       "arguments": []
+//!     }
+//!   ],
+//!   "edges": [{ "from": 4211, "to": 2, "kind": "property", "label": "field (perches)" }]
+//! }
+//! ```
+//!
+//! Node IDs are the type-level IDs of the nodes, and are stable for as long as the paths of the
+//! items they were derived from are. `fields` lists the fields of structs, unions, tuples and enum
+//! variants and `arguments` the arguments of functions, each naming the node of its type. Node and
+//! edge kinds are given by [`NodeKind::name`] and [`EdgeKind::name`](super::EdgeKind::name).
+//!
+//! [`SCHEMA_VERSION`] is bumped whenever the shape of the document changes.
+
+use serde::Serialize;
+
+use super::{NodeKind, ValueGraph, Vectorize};
+use crate::value::String;
+use crate::value::Vec;
+
+/// The version of the document schema, bumped whenever its shape changes.
+pub const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct Document {
+    pub schema_version: u32,
+    pub nodes: Vec<Node>,
+    pub edges: Vec<Edge>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct Node {
+    pub id: u32,
+    pub kind: &'static str,
+    pub label: &'static str,
+    pub clusters: &'static [&'static str],
+    pub fields: Vec<Slot>,
+    pub arguments: Vec<Slot>,
+}
+
+/// A named reference to another node, like a field and the node of its type.
+#[derive(Clone, Debug, Serialize)]
+pub struct Slot {
+    pub name: &'static str,
+    pub node: u32,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct Edge {
+    pub from: u32,
+    pub to: u32,
+    pub kind: &'static str,
+    pub label: String,
+}
+
+pub trait Json {
+    fn document() -> Document;
+}
+impl<T> Json for T
+where
+    T: ValueGraph<NodeKind>,
+    <T as ValueGraph<NodeKind>>::NodeList: Vectorize<super::Node<NodeKind>>,
+{
+    fn document() -> Document {
+        let graph = <Self as ValueGraph<NodeKind>>::value();
+        let ids = super::node_ids::<Self>();
+
+        let slots = |v: &'static [(&'static str, u32)]| {
+            v.iter()
+                .map(|(name, node)| Slot { name, node: *node })
+                .collect::<Vec<_>>()
+        };
+        let nodes = graph
+            .node_indices()
+            .map(|ix| {
+                let node = &graph[ix];
+                let (fields, arguments) = match node {
+                    NodeKind::Struct(..)
+                    | NodeKind::Union(..)
+                    | NodeKind::Tuple(..)
+                    | NodeKind::Variant(..) => (slots(node.metadata()), Vec::new()),
+                    NodeKind::Function(..) | NodeKind::AsyncFunction(..) => {
+                        (Vec::new(), slots(node.metadata()))
+                    }
+                    _ => (Vec::new(), Vec::new()),
+                };
+                Node {
+                    id: ids[ix.index()],
+                    kind: node.name(),
+                    label: node.label(),
+                    clusters: node.cluster(),
+                    fields,
+                    arguments,
+                }
+            })
+            .collect();
+
+        let edges = graph
+            .edge_weights()
+            .map(|weight| {
+                let meta = weight.meta(&graph);
+                Edge {
+                    from: weight.from,
+                    to: weight.to,
+                    kind: weight.kind.name(),
+                    label: weight.label(&meta),
+                }
+            })
+            .collect();
+
+        Document {
+            schema_version: SCHEMA_VERSION,
+            nodes,
+            edges,
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use super::class::{ClassDiagram, RelationKind};
+use super::{NodeKind, ValueGraph};
+use crate::value::format;
+use crate::value::String;
+use crate::value::Vec;
+use std::collections::BTreeMap;
+use std::string::ToString;
+use std::vec;
+
+/// Renders a graph as a Mermaid `classDiagram`.
+///
+/// Types become classes listing their fields, and the methods of their impls. Trait impls become
+/// realization arrows from the trait to the implementing type, fields composition arrows and
+/// enum variants inheritance arrows. Clusters become namespaces.
+pub trait Mermaid {
+    fn render() -> String;
+}
+impl<T> Mermaid for T
+where
+    T: ValueGraph<NodeKind>,
+{
+    fn render() -> String {
+        let graph = <Self as ValueGraph<NodeKind>>::value();
+        let diagram = ClassDiagram::new(&graph);
+
+        fn escape(s: &str) -> String {
+            s.replace('"', "#quot;")
+                .replace('<', "#lt;")
+                .replace('>', "#gt;")
+        }
+
+        let mut namespaces: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for class in &diagram.classes {
+            let fields = class
+                .fields
+                .iter()
+                .map(|(name, ty)| format!("{name}: {ty}"));
+            let methods = class.methods.iter().map(|m| {
+                let args = m
+                    .args
+                    .iter()
+                    .map(|(arg, ty)| format!("{arg}: {ty}"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let prefix = if m.is_async { "async " } else { "" };
+                match m.returns {
+                    Some(ret) => format!("{prefix}{}({args}) {ret}", m.name),
+                    None => format!("{prefix}{}({args})", m.name),
+                }
+            });
+            let members = fields
+                .chain(methods)
+                .map(|m| format!("    {}", escape(&m)))
+                .collect::<Vec<_>>();
+
+            let (id, label) = (class.index, escape(class.node.label()));
+            let class_def = if members.is_empty() {
+                format!("class N{id}[\"{label}\"]")
+            } else {
+                format!("class N{id}[\"{label}\"] {{\n{}\n}}", members.join("\n"))
+            };
+            namespaces
+                .entry(class.node.cluster().join("_"))
+                .or_default()
+                .push(class_def);
+        }
+
+        let relations = diagram.relations.iter().map(|r| {
+            let arrow = match r.kind {
+                RelationKind::Composition => "*--",
+                RelationKind::Aggregation => "o--",
+                RelationKind::Inheritance => "<|--",
+                RelationKind::Realization => "<|..",
+                RelationKind::Dependency => "..>",
+                RelationKind::Association => "-->",
+            };
+            if r.label.is_empty() {
+                format!("N{} {arrow} N{}", r.from, r.to)
+            } else {
+                format!("N{} {arrow} N{} : {}", r.from, r.to, escape(&r.label))
+            }
+        });
+
+        let indent = |s: &str, n: usize| {
+            s.lines()
+                .map(|l| format!("{}{l}", " ".repeat(n)))
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        let mut lines = vec!["classDiagram".to_string()];
+        for (namespace, classes) in namespaces {
+            if namespace.is_empty() {
+                lines.extend(classes.iter().map(|c| indent(c, 4)));
+            } else {
+                lines.push(format!("    namespace {namespace} {{"));
+                lines.extend(classes.iter().map(|c| indent(c, 8)));
+                lines.push("    }".to_string());
+            }
+        }
+        lines.extend(relations.map(|r| indent(&r, 4)));
+
+        lines.join("\n")
+    }
+}
@@ -3,16 +3,32 @@ use std::string::String;
 use std::string::ToString;
 use std::vec;
 use std::vec::Vec;
-use typosaurus::collections::graph::{Graph, OutgoingEdgeList, ValueList};
+use typosaurus::collections::graph::{Graph, ValueList};
 use typosaurus::collections::list::{self, List};
 use typosaurus::collections::Container;
 
 use crate::graph::{
-    EdgeOutput, EdgeOutputData, NodeOutput, NodeOutputData, NodeOutputKind, Unsigned,
+    Access, EdgeOutput, EdgeOutputData, EdgeOutputKind, NodeOutput, NodeOutputData, NodeOutputKind,
+    Unsigned,
 };
+use crate::op::TypedGraph;
 
+#[cfg(any(feature = "mermaid", feature = "plantuml"))]
+mod class;
+#[cfg(feature = "cypher")]
+pub mod cypher;
+#[cfg(feature = "gexf")]
+pub mod gexf;
+#[cfg(feature = "graphml")]
+pub mod graphml;
 #[cfg(feature = "graphviz")]
 pub mod graphviz;
+#[cfg(feature = "serde")]
+pub mod json;
+#[cfg(feature = "mermaid")]
+pub mod mermaid;
+#[cfg(feature = "plantuml")]
+pub mod plantuml;
 
 pub trait ValueGraph<T = NodeKind> {
     type NodeList;
@@ -20,39 +36,71 @@ pub trait ValueGraph<T = NodeKind> {
 
     fn value() -> petgraph::Graph<T, EdgeKindWithIxs<petgraph::graph::NodeIndex>>;
 }
-impl<T, N, I, O> ValueGraph<T> for Graph<N, I, O>
+impl<T, N, I, O, E> ValueGraph<T> for TypedGraph<Graph<N, I, O>, E>
 where
-    Graph<N, I, O>: ValueList + OutgoingEdgeList,
+    Graph<N, I, O>: ValueList,
     <Graph<N, I, O> as ValueList>::Out: Vectorize<Node<T>>,
-    <Graph<N, I, O> as OutgoingEdgeList>::Out: Vectorize<(u32, u32)>,
+    E: Vectorize<ConnectedEdgeKind>,
 {
     type NodeList = <Graph<N, I, O> as ValueList>::Out;
-    type EdgeList = <Graph<N, I, O> as OutgoingEdgeList>::Out;
+    type EdgeList = E;
 
     fn value() -> petgraph::Graph<T, EdgeKindWithIxs<petgraph::graph::NodeIndex>> {
-        let edges = <Self::EdgeList as Vectorize<(u32, u32)>>::to_vec();
+        let edges = <Self::EdgeList as Vectorize<ConnectedEdgeKind>>::to_vec();
         let data = <Self::NodeList as Vectorize<Node<T>>>::to_vec();
         let mut graph: petgraph::Graph<T, EdgeKindWithIxs<petgraph::graph::NodeIndex>> =
             petgraph::Graph::new();
         let mut ixs = std::collections::HashMap::new();
-        let mut nodes = std::collections::HashMap::new();
         for node in data {
             let id = node.id();
-            let (node, data) = node.swap(());
+            let (_, data) = node.swap(());
             let ix = graph.add_node(data);
-            nodes.insert(id, node);
             ixs.insert(id, ix);
         }
-        for (from, to) in edges {
-            let (a, b) = (nodes.get(&from).unwrap(), nodes.get(&to).unwrap());
-            let (from, to) = (*ixs.get(&from).unwrap(), *ixs.get(&to).unwrap());
-            graph.add_edge(from, to, ConnectedEdgeKind::implied(a, b).map(&ixs));
+        // Edges are recorded most recent first.
+        let mut connected = std::collections::HashSet::new();
+        for edge in edges.into_iter().rev() {
+            if connected.insert((edge.from, edge.to, edge.kind)) {
+                let (from, to) = (*ixs.get(&edge.from).unwrap(), *ixs.get(&edge.to).unwrap());
+                graph.add_edge(from, to, edge.map(&ixs));
+            }
         }
 
         graph
     }
 }
 
+/// The type-level IDs of the nodes of a graph, by node index.
+#[cfg(any(feature = "serde", feature = "cypher"))]
+fn node_ids<T>() -> Vec<u32>
+where
+    T: ValueGraph<NodeKind>,
+    <T as ValueGraph<NodeKind>>::NodeList: Vectorize<Node<NodeKind>>,
+{
+    // `ValueGraph::value` adds the nodes in list order.
+    <<T as ValueGraph<NodeKind>>::NodeList as Vectorize<Node<NodeKind>>>::to_vec()
+        .iter()
+        .map(Node::id)
+        .collect()
+}
+
+/// The paths the type-level IDs of the nodes of a graph are derived from, by node index.
+#[cfg(feature = "cypher")]
+fn node_paths<T>() -> Vec<&'static str>
+where
+    T: ValueGraph<NodeKind>,
+    <T as ValueGraph<NodeKind>>::NodeList: Vectorize<NodePath>,
+{
+    <<T as ValueGraph<NodeKind>>::NodeList as Vectorize<NodePath>>::to_vec()
+        .into_iter()
+        .map(|p| p.0)
+        .collect()
+}
+
+/// The path a node's ID is derived from, see [`NodeOutputData::PATH`].
+#[cfg(feature = "cypher")]
+pub struct NodePath(pub &'static str);
+
 pub trait Value<T> {
     fn value() -> T;
 }
@@ -106,13 +154,26 @@ where
     }
 }
 
-impl<Id, D> Value<Output> for EdgeOutput<Id, D>
+impl<Id, D> Value<ConnectedEdgeKind> for EdgeOutput<Id, D>
 where
-    Id: Unsigned,
     D: EdgeOutputData,
 {
-    fn value() -> Output {
-        todo!()
+    fn value() -> ConnectedEdgeKind {
+        ConnectedEdgeKind {
+            kind: <D as EdgeOutputData>::KIND.into(),
+            from: <D as EdgeOutputData>::FROM,
+            to: <D as EdgeOutputData>::TO,
+        }
+    }
+}
+
+#[cfg(feature = "cypher")]
+impl<Id, D, Mt> Value<NodePath> for NodeOutput<Id, D, Mt>
+where
+    D: NodeOutputData,
+{
+    fn value() -> NodePath {
+        NodePath(<D as NodeOutputData>::PATH)
     }
 }
 
@@ -132,13 +193,19 @@ where
             NodeOutputKind::Struct => Node::Struct(id, name, Mt::value()),
             NodeOutputKind::Generic => Node::Generic(id, name, Mt::value()),
             NodeOutputKind::Enum => Node::Enum(id, name, Mt::value()),
+            NodeOutputKind::Union => Node::Union(id, name, Mt::value()),
             NodeOutputKind::Function => Node::Function(id, name, Mt::value()),
             NodeOutputKind::AsyncFunction => Node::AsyncFunction(id, name, Mt::value()),
             NodeOutputKind::Implementation => Node::InherentImplementation(id, name, Mt::value()),
+            NodeOutputKind::TraitImplementation => Node::TraitImplementation(id, name, Mt::value()),
+            NodeOutputKind::Trait => Node::Trait(id, name, Mt::value()),
+            NodeOutputKind::Field => Node::Field(id, name, Mt::value()),
             NodeOutputKind::Primitive => Node::Primitive(id, name, Mt::value()),
             NodeOutputKind::Variant => Node::Variant(id, name, Mt::value()),
             NodeOutputKind::UnaryContainer => Node::UnaryContainer(id, name, Mt::value()),
             NodeOutputKind::BinaryContainer => Node::BinaryContainer(id, name, Mt::value()),
+            NodeOutputKind::Tuple => Node::Tuple(id, name, Mt::value()),
+            NodeOutputKind::Alias => Node::Alias(id, name, Mt::value()),
         }
     }
 }
@@ -147,29 +214,39 @@ pub enum Node<T> {
     Struct(u32, &'static str, T),
     Generic(u32, &'static str, T),
     Enum(u32, &'static str, T),
+    Union(u32, &'static str, T),
     InherentImplementation(u32, &'static str, T),
     TraitImplementation(u32, &'static str, T),
+    Trait(u32, &'static str, T),
+    Field(u32, &'static str, T),
     Function(u32, &'static str, T),
     AsyncFunction(u32, &'static str, T),
     Primitive(u32, &'static str, T),
     Variant(u32, &'static str, T),
     UnaryContainer(u32, &'static str, T),
     BinaryContainer(u32, &'static str, T),
+    Tuple(u32, &'static str, T),
+    Alias(u32, &'static str, T),
 }
 impl<T> Node<T> {
     fn id(&self) -> u32 {
         match self {
             Self::Struct(id, _, _)
             | Self::Enum(id, _, _)
+            | Self::Union(id, _, _)
             | Self::Generic(id, _, _)
             | Self::InherentImplementation(id, _, _)
             | Self::TraitImplementation(id, _, _)
+            | Self::Trait(id, _, _)
+            | Self::Field(id, _, _)
             | Self::Function(id, _, _)
             | Self::AsyncFunction(id, _, _)
             | Self::Primitive(id, _, _)
             | Self::Variant(id, _, _)
             | Self::UnaryContainer(id, _, _)
-            | Self::BinaryContainer(id, _, _) => *id,
+            | Self::BinaryContainer(id, _, _)
+            | Self::Tuple(id, _, _)
+            | Self::Alias(id, _, _) => *id,
         }
     }
 
@@ -178,96 +255,70 @@ impl<T> Node<T> {
             Self::Struct(id, s, d) => (Node::Struct(id, s, input), d),
             Self::Generic(id, s, d) => (Node::Generic(id, s, input), d),
             Self::Enum(id, s, d) => (Node::Enum(id, s, input), d),
+            Self::Union(id, s, d) => (Node::Union(id, s, input), d),
             Self::InherentImplementation(id, s, d) => {
                 (Node::InherentImplementation(id, s, input), d)
             }
             Self::TraitImplementation(id, s, d) => (Node::TraitImplementation(id, s, input), d),
+            Self::Trait(id, s, d) => (Node::Trait(id, s, input), d),
+            Self::Field(id, s, d) => (Node::Field(id, s, input), d),
             Self::Function(id, s, d) => (Node::Function(id, s, input), d),
             Self::AsyncFunction(id, s, d) => (Node::AsyncFunction(id, s, input), d),
             Self::Primitive(id, s, d) => (Node::Primitive(id, s, input), d),
             Self::Variant(id, s, d) => (Node::Variant(id, s, input), d),
             Self::UnaryContainer(id, s, d) => (Node::UnaryContainer(id, s, input), d),
             Self::BinaryContainer(id, s, d) => (Node::BinaryContainer(id, s, input), d),
+            Self::Tuple(id, s, d) => (Node::Tuple(id, s, input), d),
+            Self::Alias(id, s, d) => (Node::Alias(id, s, input), d),
         }
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum EdgeKind {
     Property,
     Variant,
     Implementation,
+    Implements,
     Function,
     AsyncFunction,
     Argument,
     Returns,
     Call,
+    Reads,
+    Writes,
+    Field,
     Contains,
     Generic,
+    Bound,
+    Associated,
+    Alias,
     Unknown,
 }
-impl EdgeKind {
-    fn implied<T>(a: &Node<T>, b: &Node<T>) -> Self {
-        match (a, b) {
-            (
-                Node::UnaryContainer { .. } | Node::BinaryContainer { .. },
-                Node::UnaryContainer { .. }
-                | Node::BinaryContainer { .. }
-                | Node::Struct { .. }
-                | Node::Enum { .. }
-                | Node::Primitive { .. }
-                | Node::Generic { .. },
-            ) => Self::Contains,
-            (Node::Enum { .. }, Node::Variant { .. }) => Self::Variant,
-            (Node::Function { .. }, Node::Function { .. }) => Self::Call,
-            (
-                Node::Struct { .. } | Node::Enum { .. },
-                Node::InherentImplementation { .. } | Node::TraitImplementation { .. },
-            ) => Self::Implementation,
-            (
-                Node::TraitImplementation { .. } | Node::InherentImplementation { .. },
-                Node::Function { .. },
-            ) => Self::Function,
-            (
-                Node::TraitImplementation { .. } | Node::InherentImplementation { .. },
-                Node::AsyncFunction { .. },
-            ) => Self::AsyncFunction,
-            (
-                Node::Function { .. } | Node::AsyncFunction { .. },
-                Node::Struct { .. }
-                | Node::Enum { .. }
-                | Node::Primitive(_, _, _)
-                | Node::UnaryContainer { .. }
-                | Node::BinaryContainer { .. }
-                | Node::Generic { .. },
-            ) => Self::Returns,
-            (
-                Node::Variant { .. } | Node::Struct { .. } | Node::Enum { .. },
-                Node::Struct { .. }
-                | Node::Enum { .. }
-                | Node::Primitive(_, _, _)
-                | Node::UnaryContainer { .. }
-                | Node::BinaryContainer { .. },
-            ) => Self::Property,
-            (
-                Node::Struct { .. }
-                | Node::Enum { .. }
-                | Node::Primitive(_, _, _)
-                | Node::BinaryContainer { .. }
-                | Node::UnaryContainer { .. }
-                | Node::Generic { .. },
-                Node::Function { .. } | Node::AsyncFunction { .. },
-            ) => Self::Argument,
-            (
-                Node::Generic { .. },
-                Node::InherentImplementation { .. } | Node::TraitImplementation { .. },
-            ) => Self::Generic,
-            (Node::Variant { .. } | Node::Struct { .. }, Node::Generic { .. }) => Self::Property,
-
-            _ => Self::Unknown,
+impl From<EdgeOutputKind> for EdgeKind {
+    fn from(kind: EdgeOutputKind) -> Self {
+        match kind {
+            EdgeOutputKind::MethodOf => Self::Function,
+            EdgeOutputKind::AsyncMethodOf => Self::AsyncFunction,
+            EdgeOutputKind::PropertyOf => Self::Property,
+            EdgeOutputKind::VariantOf => Self::Variant,
+            EdgeOutputKind::FieldOf => Self::Field,
+            EdgeOutputKind::Contains => Self::Contains,
+            EdgeOutputKind::Invokes => Self::Call,
+            EdgeOutputKind::Accesses(Access::Read) => Self::Reads,
+            EdgeOutputKind::Accesses(Access::Write) => Self::Writes,
+            EdgeOutputKind::ImplementedBy => Self::Implementation,
+            EdgeOutputKind::Implements => Self::Implements,
+            EdgeOutputKind::ArgumentOf => Self::Argument,
+            EdgeOutputKind::Provides => Self::Returns,
+            EdgeOutputKind::Bound => Self::Bound,
+            EdgeOutputKind::Associates => Self::Associated,
+            EdgeOutputKind::Aliases => Self::Alias,
         }
     }
+}
 
+impl EdgeKind {
     pub fn arrowhead(&self) -> &str {
         match self {
             Self::Property | Self::Contains => "dot",
@@ -275,13 +326,44 @@ impl EdgeKind {
             Self::Function => "odot",
             Self::AsyncFunction => "odot",
             Self::Implementation => "dot",
+            Self::Implements => "empty",
             Self::Argument => "normal",
             Self::Generic => "normal",
+            Self::Bound => "empty",
+            Self::Associated => "diamond",
+            Self::Alias => "onormal",
             Self::Returns => "vee",
+            Self::Call => "onormal",
+            Self::Reads | Self::Writes => "normal",
+            Self::Field => "dot",
             _ => "normal",
         }
     }
 
+    /// The snake case name of the variant, used by the data exports.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Property => "property",
+            Self::Variant => "variant",
+            Self::Implementation => "implementation",
+            Self::Implements => "implements",
+            Self::Function => "function",
+            Self::AsyncFunction => "async_function",
+            Self::Argument => "argument",
+            Self::Returns => "returns",
+            Self::Call => "call",
+            Self::Reads => "reads",
+            Self::Writes => "writes",
+            Self::Field => "field",
+            Self::Contains => "contains",
+            Self::Generic => "generic",
+            Self::Bound => "bound",
+            Self::Associated => "associated",
+            Self::Alias => "alias",
+            Self::Unknown => "unknown",
+        }
+    }
+
     pub fn weight(&self) -> u8 {
         1
     }
@@ -294,39 +376,26 @@ impl EdgeKind {
         match self {
             Self::Returns => "#7aa2f7",
             Self::Implementation => "#9ece6a",
+            Self::Implements => "#73daca",
             Self::Function => "#e0af68",
             Self::AsyncFunction => "#ff9e64",
             Self::Property => "#7aa2f67",
             Self::Contains => "#7aa2f67",
             Self::Argument => "#e0af68",
             Self::Generic => "#bb9af7",
+            Self::Bound => "#bb9af7",
+            Self::Associated => "#73daca",
+            Self::Alias => "#c0caf5",
             Self::Variant => "#7dcfff",
+            Self::Call => "#f7768e",
+            Self::Reads => "#73daca",
+            Self::Writes => "#ff9e64",
+            Self::Field => "#7aa2f7",
             _ => "black",
         }
     }
 }
 
-#[derive(Clone, Copy, Debug)]
-pub enum Output {
-    Struct { id: u32, name: &'static str },
-    Enum { id: u32, name: &'static str },
-    InherentImplementation { id: u32 },
-    TraitImplementation { id: u32, trait_name: &'static str },
-    Method { id: u32, name: &'static str },
-}
-
-impl Output {
-    pub fn id(&self) -> u32 {
-        match *self {
-            Self::InherentImplementation { id } => id,
-            Self::Enum { id, .. } => id,
-            Self::Struct { id, .. } => id,
-            Self::TraitImplementation { id, .. } => id,
-            Self::Method { id, .. } => id,
-        }
-    }
-}
-
 #[derive(Clone, Debug)]
 pub enum NodeKind {
     Struct(
@@ -344,12 +413,33 @@ pub enum NodeKind {
         &'static [&'static str],
         &'static [(&'static str, u32)],
     ),
+    Union(
+        &'static str,
+        &'static [&'static str],
+        &'static [(&'static str, u32)],
+    ),
     Variant(
         &'static str,
         &'static [&'static str],
         &'static [(&'static str, u32)],
     ),
-    Implementation(&'static str, &'static [&'static str]),
+    Implementation(
+        &'static str,
+        &'static [&'static str],
+        &'static [(&'static str, u32)],
+    ),
+    TraitImplementation(
+        &'static str,
+        &'static [&'static str],
+        &'static str,
+        &'static [(&'static str, u32)],
+    ),
+    Trait(
+        &'static str,
+        &'static [&'static str],
+        &'static [(&'static str, u32)],
+    ),
+    Field(&'static str, &'static [&'static str]),
     Function(
         &'static str,
         &'static [&'static str],
@@ -363,20 +453,85 @@ pub enum NodeKind {
     Primitive(&'static str, &'static [&'static str]),
     UnaryContainer(&'static str, &'static [&'static str]),
     BinaryContainer(&'static str, &'static [&'static str]),
+    Tuple(
+        &'static str,
+        &'static [&'static str],
+        &'static [(&'static str, u32)],
+    ),
+    Alias(&'static str, &'static [&'static str]),
 }
 impl NodeKind {
     pub fn label(&self) -> &'static str {
         match self {
             Self::Struct(s, _, _)
             | Self::Enum(s, _, _)
+            | Self::Union(s, _, _)
             | Self::Generic(s, _, _)
             | Self::Variant(s, _, _)
-            | Self::Implementation(s, _)
+            | Self::Implementation(s, _, _)
+            | Self::TraitImplementation(s, _, _, _)
+            | Self::Trait(s, _, _)
+            | Self::Field(s, _)
             | Self::Function(s, _, _)
             | Self::AsyncFunction(s, _, _)
             | Self::Primitive(s, _)
             | Self::UnaryContainer(s, _)
-            | Self::BinaryContainer(s, _) => s,
+            | Self::BinaryContainer(s, _)
+            | Self::Tuple(s, _, _)
+            | Self::Alias(s, _) => s,
+        }
+    }
+
+    /// The snake case name of the variant, used by the data exports.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Struct(..) => "struct",
+            Self::Generic(..) => "generic",
+            Self::Enum(..) => "enum",
+            Self::Union(..) => "union",
+            Self::Variant(..) => "variant",
+            Self::Implementation(..) => "implementation",
+            Self::TraitImplementation(..) => "trait_implementation",
+            Self::Trait(..) => "trait",
+            Self::Field(..) => "field",
+            Self::Function(..) => "function",
+            Self::AsyncFunction(..) => "async_function",
+            Self::Primitive(..) => "primitive",
+            Self::UnaryContainer(..) => "unary_container",
+            Self::BinaryContainer(..) => "binary_container",
+            Self::Tuple(..) => "tuple",
+            Self::Alias(..) => "alias",
+        }
+    }
+
+    /// The `(name, node ID)` slots of the node: fields of structs, unions, tuples and variants,
+    /// arguments of functions, and the edge labels of the other kinds that have any.
+    pub fn metadata(&self) -> &'static [(&'static str, u32)] {
+        match self {
+            Self::Struct(_, _, v)
+            | Self::Enum(_, _, v)
+            | Self::Union(_, _, v)
+            | Self::Generic(_, _, v)
+            | Self::Variant(_, _, v)
+            | Self::Implementation(_, _, v)
+            | Self::TraitImplementation(_, _, _, v)
+            | Self::Trait(_, _, v)
+            | Self::Function(_, _, v)
+            | Self::AsyncFunction(_, _, v)
+            | Self::Tuple(_, _, v) => v,
+            Self::Field(_, _)
+            | Self::Primitive(_, _)
+            | Self::UnaryContainer(_, _)
+            | Self::BinaryContainer(_, _)
+            | Self::Alias(_, _) => &[],
+        }
+    }
+
+    /// The implemented trait, as written in the impl, for trait implementations.
+    pub fn trait_path(&self) -> Option<&'static str> {
+        match self {
+            Self::TraitImplementation(_, _, t, _) => Some(t),
+            _ => None,
         }
     }
 
@@ -386,12 +541,18 @@ impl NodeKind {
             Self::Generic(_, _, _) => "diamond",
             Self::UnaryContainer(_, _) => "box3d",
             Self::BinaryContainer(_, _) => "box3d",
+            Self::Tuple(_, _, _) => "box3d",
             Self::Enum(_, _, _) => "folder",
+            Self::Union(_, _, _) => "tab",
             Self::Variant(_, _, _) => "note",
-            Self::Implementation(_, _) => "cylinder",
+            Self::Implementation(_, _, _) => "cylinder",
+            Self::TraitImplementation(_, _, _, _) => "cylinder",
+            Self::Trait(_, _, _) => "component",
+            Self::Field(_, _) => "plain",
             Self::Function(_, _, _) => "ellipse",
             Self::AsyncFunction(_, _, _) => "ellipse",
             Self::Primitive(_, _) => "square",
+            Self::Alias(_, _) => "cds",
         }
     }
 
@@ -401,12 +562,18 @@ impl NodeKind {
             Self::Generic(_, _, _) => "#bb9af7",
             Self::UnaryContainer(_, _) => "#7aa2f7",
             Self::BinaryContainer(_, _) => "#7aa2f7",
+            Self::Tuple(_, _, _) => "#7aa2f7",
             Self::Enum(_, _, _) => "#7dcfff",
+            Self::Union(_, _, _) => "#2ac3de",
             Self::Variant(_, _, _) => "#7dcfff",
-            Self::Implementation(_, _) => "#9ece6a",
+            Self::Implementation(_, _, _) => "#9ece6a",
+            Self::TraitImplementation(_, _, _, _) => "#9ece6a",
+            Self::Trait(_, _, _) => "#73daca",
+            Self::Field(_, _) => "#7aa2f7",
             Self::Function(_, _, _) => "#e0af68",
             Self::AsyncFunction(_, _, _) => "#ff9e64",
             Self::Primitive(_, _) => "#c0caf5",
+            Self::Alias(_, _) => "#c0caf5",
         }
     }
 
@@ -414,14 +581,20 @@ impl NodeKind {
         match self {
             Self::Struct(_, c, _)
             | Self::Enum(_, c, _)
+            | Self::Union(_, c, _)
             | Self::Generic(_, c, _)
             | Self::Variant(_, c, _)
-            | Self::Implementation(_, c)
+            | Self::Implementation(_, c, _)
+            | Self::TraitImplementation(_, c, _, _)
+            | Self::Trait(_, c, _)
+            | Self::Field(_, c)
             | Self::Function(_, c, _)
             | Self::AsyncFunction(_, c, _)
             | Self::Primitive(_, c)
             | Self::UnaryContainer(_, c)
-            | Self::BinaryContainer(_, c) => c,
+            | Self::BinaryContainer(_, c)
+            | Self::Tuple(_, c, _)
+            | Self::Alias(_, c) => c,
         }
     }
 }
@@ -441,18 +614,59 @@ impl<T> EdgeKindWithIxs<T> {
             Property => format!("field ({meta})"),
             Variant => "variant".to_string(),
             Implementation => "impl".to_string(),
-            Function => "fn".to_string(),
-            AsyncFunction => "async fn".to_string(),
+            Implements if meta.is_empty() => "implements".to_string(),
+            Implements => format!("implements ({meta})"),
+            Function if meta.is_empty() => "fn".to_string(),
+            Function => format!("fn ({meta})"),
+            AsyncFunction if meta.is_empty() => "async fn".to_string(),
+            AsyncFunction => format!("async fn ({meta})"),
             Argument => format!("arg ({meta})"),
             Returns => "returns".to_string(),
             Call => "calls".to_string(),
+            Reads => "reads".to_string(),
+            Writes => "writes".to_string(),
+            Field => "field".to_string(),
             Contains => "content".to_string(),
             Generic => "generic".to_string(),
+            Bound if meta.is_empty() => "bound".to_string(),
+            Bound => format!("bound ({meta})"),
+            Associated => meta.to_string(),
+            Alias => "alias".to_string(),
             Unknown => "unknown".to_string(),
         }
     }
 }
 
+impl EdgeKindWithIxs<petgraph::graph::NodeIndex> {
+    /// The names the edge has in the metadata of its endpoints, like the name of the field for a
+    /// property or of the argument for an argument edge.
+    pub fn meta(
+        &self,
+        graph: &petgraph::Graph<NodeKind, EdgeKindWithIxs<petgraph::graph::NodeIndex>>,
+    ) -> String {
+        let (node, id) = match self.kind {
+            EdgeKind::Argument => (&graph[self.to_ix], self.from),
+            _ => (&graph[self.from_ix], self.to),
+        };
+        node.metadata()
+            .iter()
+            .filter(|(_, n)| *n == id)
+            .map(|(s, _)| *s)
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// Escapes text for use in XML content and attribute values.
+#[cfg(any(feature = "graphml", feature = "gexf"))]
+fn escape_xml(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct ConnectedEdgeKind {
     pub kind: EdgeKind,
@@ -460,14 +674,6 @@ pub struct ConnectedEdgeKind {
     pub to: u32,
 }
 impl ConnectedEdgeKind {
-    pub fn implied<T>(a: &Node<T>, b: &Node<T>) -> Self {
-        Self {
-            kind: EdgeKind::implied(a, b),
-            from: a.id(),
-            to: b.id(),
-        }
-    }
-
     pub fn map<T: Copy>(self, ids: &std::collections::HashMap<u32, T>) -> EdgeKindWithIxs<T> {
         EdgeKindWithIxs {
             kind: self.kind,
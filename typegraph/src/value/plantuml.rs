@@ -0,0 +1,126 @@
+use super::class::{ClassDiagram, RelationKind};
+use super::{NodeKind, ValueGraph};
+use crate::value::format;
+use crate::value::String;
+use crate::value::Vec;
+use std::collections::BTreeMap;
+use std::string::ToString;
+use std::vec;
+
+/// Renders a graph as a PlantUML class diagram.
+///
+/// The diagram has the same classes and relations as the Mermaid export. Clusters become nested
+/// packages, traits become interfaces, and the kinds of node that aren't types of their own, like
+/// generic parameters, containers and primitives, are marked with stereotypes.
+pub trait PlantUml {
+    fn render() -> String;
+}
+impl<T> PlantUml for T
+where
+    T: ValueGraph<NodeKind>,
+{
+    fn render() -> String {
+        let graph = <Self as ValueGraph<NodeKind>>::value();
+        let diagram = ClassDiagram::new(&graph);
+
+        let mut packages: BTreeMap<&[&str], Vec<String>> = BTreeMap::new();
+        for class in &diagram.classes {
+            let keyword = match class.node {
+                NodeKind::Trait(..) => "interface",
+                NodeKind::Enum(..) => "enum",
+                _ => "class",
+            };
+            let stereotype = match class.node {
+                NodeKind::Generic(..) => " <<generic>>",
+                NodeKind::UnaryContainer(..) | NodeKind::BinaryContainer(..) => " <<container>>",
+                NodeKind::Primitive(..) => " <<primitive>>",
+                NodeKind::Tuple(..) => " <<tuple>>",
+                NodeKind::Union(..) => " <<union>>",
+                NodeKind::Variant(..) => " <<variant>>",
+                NodeKind::Function(..) => " <<fn>>",
+                NodeKind::AsyncFunction(..) => " <<async fn>>",
+                NodeKind::Alias(..) => " <<alias>>",
+                _ => "",
+            };
+            let fields = class
+                .fields
+                .iter()
+                .map(|(name, ty)| format!("{name}: {ty}"));
+            let methods = class.methods.iter().map(|m| {
+                let args = m
+                    .args
+                    .iter()
+                    .map(|(arg, ty)| format!("{arg}: {ty}"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let prefix = if m.is_async { "async " } else { "" };
+                match m.returns {
+                    Some(ret) => format!("{prefix}{}({args}): {ret}", m.name),
+                    None => format!("{prefix}{}({args})", m.name),
+                }
+            });
+            let members = fields
+                .chain(methods)
+                .map(|m| format!("    {m}"))
+                .collect::<Vec<_>>();
+
+            let (id, label) = (class.index, class.node.label().replace('"', "'"));
+            let class_def = if members.is_empty() {
+                format!("{keyword} \"{label}\" as N{id}{stereotype}")
+            } else {
+                format!(
+                    "{keyword} \"{label}\" as N{id}{stereotype} {{\n{}\n}}",
+                    members.join("\n")
+                )
+            };
+            packages
+                .entry(class.node.cluster())
+                .or_default()
+                .push(class_def);
+        }
+
+        let indent = |s: &str, n: usize| {
+            s.lines()
+                .map(|l| format!("{}{l}", " ".repeat(4 * n)))
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        let mut lines = vec!["@startuml".to_string()];
+        // Packages are visited in path order, so nested packages follow their parents.
+        let mut open: Vec<&str> = vec![];
+        for (path, classes) in packages {
+            let common = open.iter().zip(path).take_while(|(a, b)| *a == *b).count();
+            while open.len() > common {
+                open.pop();
+                lines.push(indent("}", open.len()));
+            }
+            for package in &path[common..] {
+                lines.push(indent(&format!("package {package} {{"), open.len()));
+                open.push(package);
+            }
+            lines.extend(classes.iter().map(|c| indent(c, open.len())));
+        }
+        while open.pop().is_some() {
+            lines.push(indent("}", open.len()));
+        }
+
+        lines.extend(diagram.relations.iter().map(|r| {
+            let arrow = match r.kind {
+                RelationKind::Composition => "*--",
+                RelationKind::Aggregation => "o--",
+                RelationKind::Inheritance => "<|--",
+                RelationKind::Realization => "<|..",
+                RelationKind::Dependency => "..>",
+                RelationKind::Association => "-->",
+            };
+            if r.label.is_empty() {
+                format!("N{} {arrow} N{}", r.from, r.to)
+            } else {
+                format!("N{} {arrow} N{} : {}", r.from, r.to, r.label)
+            }
+        }));
+        lines.push("@enduml".to_string());
+
+        lines.join("\n")
+    }
+}